@@ -11,6 +11,7 @@ use {
     solana_program_test::tokio::time,
     solana_sdk::{
         account::Account as BaseAccount,
+        clock::Slot,
         compute_budget::ComputeBudgetInstruction,
         hash::Hash,
         instruction::{AccountMeta, Instruction},
@@ -30,6 +31,7 @@ use {
     },
     spl_token_2022::{
         extension::{
+            approval_expiry,
             confidential_transfer::{
                 self,
                 account_info::{
@@ -46,8 +48,10 @@ use {
                 self, account_info::WithheldTokensInfo, ConfidentialTransferFeeAmount,
                 ConfidentialTransferFeeConfig,
             },
-            cpi_guard, default_account_state, group_member_pointer, group_pointer,
-            interest_bearing_mint, memo_transfer, metadata_pointer, transfer_fee, transfer_hook,
+            cpi_guard, default_account_state, distribution, group_member_pointer, group_pointer,
+            interest_bearing_mint, max_supply, memo_transfer, metadata_pointer, pausable,
+            pending_transfer, royalty, secondary_authority, snapshot, soul_bound, timed_freeze,
+            transfer_fee, transfer_hook, transfer_limit, transfer_restriction, vesting,
             BaseStateWithExtensions, Extension, ExtensionType, StateWithExtensionsOwned,
         },
         instruction, offchain,
@@ -137,184 +141,7 @@ impl PartialEq for TokenError {
 }
 
 /// Encapsulates initializing an extension
-#[derive(Clone, Debug, PartialEq)]
-pub enum ExtensionInitializationParams {
-    ConfidentialTransferMint {
-        authority: Option<Pubkey>,
-        auto_approve_new_accounts: bool,
-        auditor_elgamal_pubkey: Option<PodElGamalPubkey>,
-    },
-    DefaultAccountState {
-        state: AccountState,
-    },
-    MintCloseAuthority {
-        close_authority: Option<Pubkey>,
-    },
-    TransferFeeConfig {
-        transfer_fee_config_authority: Option<Pubkey>,
-        withdraw_withheld_authority: Option<Pubkey>,
-        transfer_fee_basis_points: u16,
-        maximum_fee: u64,
-    },
-    InterestBearingConfig {
-        rate_authority: Option<Pubkey>,
-        rate: i16,
-    },
-    NonTransferable,
-    PermanentDelegate {
-        delegate: Pubkey,
-    },
-    TransferHook {
-        authority: Option<Pubkey>,
-        program_id: Option<Pubkey>,
-    },
-    MetadataPointer {
-        authority: Option<Pubkey>,
-        metadata_address: Option<Pubkey>,
-    },
-    ConfidentialTransferFeeConfig {
-        authority: Option<Pubkey>,
-        withdraw_withheld_authority_elgamal_pubkey: PodElGamalPubkey,
-    },
-    GroupPointer {
-        authority: Option<Pubkey>,
-        group_address: Option<Pubkey>,
-    },
-    GroupMemberPointer {
-        authority: Option<Pubkey>,
-        member_address: Option<Pubkey>,
-    },
-}
-impl ExtensionInitializationParams {
-    /// Get the extension type associated with the init params
-    pub fn extension(&self) -> ExtensionType {
-        match self {
-            Self::ConfidentialTransferMint { .. } => ExtensionType::ConfidentialTransferMint,
-            Self::DefaultAccountState { .. } => ExtensionType::DefaultAccountState,
-            Self::MintCloseAuthority { .. } => ExtensionType::MintCloseAuthority,
-            Self::TransferFeeConfig { .. } => ExtensionType::TransferFeeConfig,
-            Self::InterestBearingConfig { .. } => ExtensionType::InterestBearingConfig,
-            Self::NonTransferable => ExtensionType::NonTransferable,
-            Self::PermanentDelegate { .. } => ExtensionType::PermanentDelegate,
-            Self::TransferHook { .. } => ExtensionType::TransferHook,
-            Self::MetadataPointer { .. } => ExtensionType::MetadataPointer,
-            Self::ConfidentialTransferFeeConfig { .. } => {
-                ExtensionType::ConfidentialTransferFeeConfig
-            }
-            Self::GroupPointer { .. } => ExtensionType::GroupPointer,
-            Self::GroupMemberPointer { .. } => ExtensionType::GroupMemberPointer,
-        }
-    }
-    /// Generate an appropriate initialization instruction for the given mint
-    pub fn instruction(
-        self,
-        token_program_id: &Pubkey,
-        mint: &Pubkey,
-    ) -> Result<Instruction, ProgramError> {
-        match self {
-            Self::ConfidentialTransferMint {
-                authority,
-                auto_approve_new_accounts,
-                auditor_elgamal_pubkey,
-            } => confidential_transfer::instruction::initialize_mint(
-                token_program_id,
-                mint,
-                authority,
-                auto_approve_new_accounts,
-                auditor_elgamal_pubkey,
-            ),
-            Self::DefaultAccountState { state } => {
-                default_account_state::instruction::initialize_default_account_state(
-                    token_program_id,
-                    mint,
-                    &state,
-                )
-            }
-            Self::MintCloseAuthority { close_authority } => {
-                instruction::initialize_mint_close_authority(
-                    token_program_id,
-                    mint,
-                    close_authority.as_ref(),
-                )
-            }
-            Self::TransferFeeConfig {
-                transfer_fee_config_authority,
-                withdraw_withheld_authority,
-                transfer_fee_basis_points,
-                maximum_fee,
-            } => transfer_fee::instruction::initialize_transfer_fee_config(
-                token_program_id,
-                mint,
-                transfer_fee_config_authority.as_ref(),
-                withdraw_withheld_authority.as_ref(),
-                transfer_fee_basis_points,
-                maximum_fee,
-            ),
-            Self::InterestBearingConfig {
-                rate_authority,
-                rate,
-            } => interest_bearing_mint::instruction::initialize(
-                token_program_id,
-                mint,
-                rate_authority,
-                rate,
-            ),
-            Self::NonTransferable => {
-                instruction::initialize_non_transferable_mint(token_program_id, mint)
-            }
-            Self::PermanentDelegate { delegate } => {
-                instruction::initialize_permanent_delegate(token_program_id, mint, &delegate)
-            }
-            Self::TransferHook {
-                authority,
-                program_id,
-            } => transfer_hook::instruction::initialize(
-                token_program_id,
-                mint,
-                authority,
-                program_id,
-            ),
-            Self::MetadataPointer {
-                authority,
-                metadata_address,
-            } => metadata_pointer::instruction::initialize(
-                token_program_id,
-                mint,
-                authority,
-                metadata_address,
-            ),
-            Self::ConfidentialTransferFeeConfig {
-                authority,
-                withdraw_withheld_authority_elgamal_pubkey,
-            } => {
-                confidential_transfer_fee::instruction::initialize_confidential_transfer_fee_config(
-                    token_program_id,
-                    mint,
-                    authority,
-                    withdraw_withheld_authority_elgamal_pubkey,
-                )
-            }
-            Self::GroupPointer {
-                authority,
-                group_address,
-            } => group_pointer::instruction::initialize(
-                token_program_id,
-                mint,
-                authority,
-                group_address,
-            ),
-            Self::GroupMemberPointer {
-                authority,
-                member_address,
-            } => group_member_pointer::instruction::initialize(
-                token_program_id,
-                mint,
-                authority,
-                member_address,
-            ),
-        }
-    }
-}
+pub use spl_token_2022::mint_builder::MintExtension as ExtensionInitializationParams;
 
 pub type TokenResult<T> = Result<T, TokenError>;
 
@@ -1851,6 +1678,648 @@ where
         .await
     }
 
+    /// Update the maximum supply
+    pub async fn update_max_supply<S: Signers>(
+        &self,
+        authority: &Pubkey,
+        new_max_supply: u64,
+        signing_keypairs: &S,
+    ) -> TokenResult<T::Output> {
+        let signing_pubkeys = signing_keypairs.pubkeys();
+        let multisig_signers = self.get_multisig_signers(authority, &signing_pubkeys);
+
+        self.process_ixs(
+            &[max_supply::instruction::update(
+                &self.program_id,
+                self.get_address(),
+                authority,
+                &multisig_signers,
+                new_max_supply,
+            )?],
+            signing_keypairs,
+        )
+        .await
+    }
+
+    /// Create a vesting schedule on a token account, replacing any existing
+    /// schedule
+    #[allow(clippy::too_many_arguments)]
+    pub async fn create_vesting_schedule<S: Signers>(
+        &self,
+        account: &Pubkey,
+        authority: &Pubkey,
+        total_amount: u64,
+        start_timestamp: i64,
+        cliff_timestamp: i64,
+        end_timestamp: i64,
+        signing_keypairs: &S,
+    ) -> TokenResult<T::Output> {
+        let signing_pubkeys = signing_keypairs.pubkeys();
+        let multisig_signers = self.get_multisig_signers(authority, &signing_pubkeys);
+
+        self.process_ixs(
+            &[vesting::instruction::create_schedule(
+                &self.program_id,
+                account,
+                self.get_address(),
+                authority,
+                &multisig_signers,
+                total_amount,
+                start_timestamp,
+                cliff_timestamp,
+                end_timestamp,
+            )?],
+            signing_keypairs,
+        )
+        .await
+    }
+
+    /// Revoke the vesting schedule on a token account
+    pub async fn revoke_vesting_schedule<S: Signers>(
+        &self,
+        account: &Pubkey,
+        authority: &Pubkey,
+        signing_keypairs: &S,
+    ) -> TokenResult<T::Output> {
+        let signing_pubkeys = signing_keypairs.pubkeys();
+        let multisig_signers = self.get_multisig_signers(authority, &signing_pubkeys);
+
+        self.process_ixs(
+            &[vesting::instruction::revoke_schedule(
+                &self.program_id,
+                account,
+                self.get_address(),
+                authority,
+                &multisig_signers,
+            )?],
+            signing_keypairs,
+        )
+        .await
+    }
+
+    /// Add a token account to the mint's transfer allowlist
+    pub async fn add_to_allowlist<S: Signers>(
+        &self,
+        account: &Pubkey,
+        authority: &Pubkey,
+        signing_keypairs: &S,
+    ) -> TokenResult<T::Output> {
+        let signing_pubkeys = signing_keypairs.pubkeys();
+        let multisig_signers = self.get_multisig_signers(authority, &signing_pubkeys);
+
+        self.process_ixs(
+            &[transfer_restriction::instruction::add_to_allowlist(
+                &self.program_id,
+                account,
+                self.get_address(),
+                authority,
+                &multisig_signers,
+            )?],
+            signing_keypairs,
+        )
+        .await
+    }
+
+    /// Remove a token account from the mint's transfer allowlist
+    pub async fn remove_from_allowlist<S: Signers>(
+        &self,
+        account: &Pubkey,
+        authority: &Pubkey,
+        signing_keypairs: &S,
+    ) -> TokenResult<T::Output> {
+        let signing_pubkeys = signing_keypairs.pubkeys();
+        let multisig_signers = self.get_multisig_signers(authority, &signing_pubkeys);
+
+        self.process_ixs(
+            &[transfer_restriction::instruction::remove_from_allowlist(
+                &self.program_id,
+                account,
+                self.get_address(),
+                authority,
+                &multisig_signers,
+            )?],
+            signing_keypairs,
+        )
+        .await
+    }
+
+    /// Add a token account to the mint's transfer denylist
+    pub async fn add_to_denylist<S: Signers>(
+        &self,
+        account: &Pubkey,
+        authority: &Pubkey,
+        signing_keypairs: &S,
+    ) -> TokenResult<T::Output> {
+        let signing_pubkeys = signing_keypairs.pubkeys();
+        let multisig_signers = self.get_multisig_signers(authority, &signing_pubkeys);
+
+        self.process_ixs(
+            &[transfer_restriction::instruction::add_to_denylist(
+                &self.program_id,
+                account,
+                self.get_address(),
+                authority,
+                &multisig_signers,
+            )?],
+            signing_keypairs,
+        )
+        .await
+    }
+
+    /// Remove a token account from the mint's transfer denylist
+    pub async fn remove_from_denylist<S: Signers>(
+        &self,
+        account: &Pubkey,
+        authority: &Pubkey,
+        signing_keypairs: &S,
+    ) -> TokenResult<T::Output> {
+        let signing_pubkeys = signing_keypairs.pubkeys();
+        let multisig_signers = self.get_multisig_signers(authority, &signing_pubkeys);
+
+        self.process_ixs(
+            &[transfer_restriction::instruction::remove_from_denylist(
+                &self.program_id,
+                account,
+                self.get_address(),
+                authority,
+                &multisig_signers,
+            )?],
+            signing_keypairs,
+        )
+        .await
+    }
+
+    /// Update the transfer limits
+    pub async fn update_transfer_limits<S: Signers>(
+        &self,
+        authority: &Pubkey,
+        max_per_transfer: u64,
+        daily_cap: u64,
+        signing_keypairs: &S,
+    ) -> TokenResult<T::Output> {
+        let signing_pubkeys = signing_keypairs.pubkeys();
+        let multisig_signers = self.get_multisig_signers(authority, &signing_pubkeys);
+
+        self.process_ixs(
+            &[transfer_limit::instruction::update_limits(
+                &self.program_id,
+                self.get_address(),
+                authority,
+                &multisig_signers,
+                max_per_transfer,
+                daily_cap,
+            )?],
+            signing_keypairs,
+        )
+        .await
+    }
+
+    /// Exempt a token account from the transfer limits
+    pub async fn add_transfer_limit_exemption<S: Signers>(
+        &self,
+        account: &Pubkey,
+        authority: &Pubkey,
+        signing_keypairs: &S,
+    ) -> TokenResult<T::Output> {
+        let signing_pubkeys = signing_keypairs.pubkeys();
+        let multisig_signers = self.get_multisig_signers(authority, &signing_pubkeys);
+
+        self.process_ixs(
+            &[transfer_limit::instruction::add_exemption(
+                &self.program_id,
+                account,
+                self.get_address(),
+                authority,
+                &multisig_signers,
+            )?],
+            signing_keypairs,
+        )
+        .await
+    }
+
+    /// Subject a token account to the transfer limits again
+    pub async fn remove_transfer_limit_exemption<S: Signers>(
+        &self,
+        account: &Pubkey,
+        authority: &Pubkey,
+        signing_keypairs: &S,
+    ) -> TokenResult<T::Output> {
+        let signing_pubkeys = signing_keypairs.pubkeys();
+        let multisig_signers = self.get_multisig_signers(authority, &signing_pubkeys);
+
+        self.process_ixs(
+            &[transfer_limit::instruction::remove_exemption(
+                &self.program_id,
+                account,
+                self.get_address(),
+                authority,
+                &multisig_signers,
+            )?],
+            signing_keypairs,
+        )
+        .await
+    }
+
+    /// Create a snapshot of all balances
+    pub async fn create_snapshot<S: Signers>(
+        &self,
+        authority: &Pubkey,
+        signing_keypairs: &S,
+    ) -> TokenResult<T::Output> {
+        let signing_pubkeys = signing_keypairs.pubkeys();
+        let multisig_signers = self.get_multisig_signers(authority, &signing_pubkeys);
+
+        self.process_ixs(
+            &[snapshot::instruction::create_snapshot(
+                &self.program_id,
+                self.get_address(),
+                authority,
+                &multisig_signers,
+            )?],
+            signing_keypairs,
+        )
+        .await
+    }
+
+    /// Fund a new distribution from an account owned by the authority
+    pub async fn fund_distribution<S: Signers>(
+        &self,
+        source: &Pubkey,
+        authority: &Pubkey,
+        amount: u64,
+        signing_keypairs: &S,
+    ) -> TokenResult<T::Output> {
+        let signing_pubkeys = signing_keypairs.pubkeys();
+        let multisig_signers = self.get_multisig_signers(authority, &signing_pubkeys);
+
+        self.process_ixs(
+            &[distribution::instruction::fund_distribution(
+                &self.program_id,
+                source,
+                self.get_address(),
+                authority,
+                &multisig_signers,
+                amount,
+            )?],
+            signing_keypairs,
+        )
+        .await
+    }
+
+    /// Claim an account's share of the latest distribution
+    pub async fn claim_distribution(&self, account: &Pubkey) -> TokenResult<T::Output> {
+        self.process_ixs::<[&dyn Signer; 0]>(
+            &[distribution::instruction::claim(
+                &self.program_id,
+                account,
+                self.get_address(),
+            )?],
+            &[],
+        )
+        .await
+    }
+
+    /// Sweep the unclaimed rewards of the latest distribution into an account
+    pub async fn sweep_distribution<S: Signers>(
+        &self,
+        destination: &Pubkey,
+        authority: &Pubkey,
+        signing_keypairs: &S,
+    ) -> TokenResult<T::Output> {
+        let signing_pubkeys = signing_keypairs.pubkeys();
+        let multisig_signers = self.get_multisig_signers(authority, &signing_pubkeys);
+
+        self.process_ixs(
+            &[distribution::instruction::sweep(
+                &self.program_id,
+                destination,
+                self.get_address(),
+                authority,
+                &multisig_signers,
+            )?],
+            signing_keypairs,
+        )
+        .await
+    }
+
+    /// Approve a delegate to spend tokens until the given slot
+    pub async fn approve_with_expiry<S: Signers>(
+        &self,
+        source: &Pubkey,
+        delegate: &Pubkey,
+        authority: &Pubkey,
+        amount: u64,
+        expiry_slot: Slot,
+        signing_keypairs: &S,
+    ) -> TokenResult<T::Output> {
+        let signing_pubkeys = signing_keypairs.pubkeys();
+        let multisig_signers = self.get_multisig_signers(authority, &signing_pubkeys);
+        let decimals = self.decimals.ok_or(TokenError::MissingDecimals)?;
+
+        self.process_ixs(
+            &[approval_expiry::instruction::approve(
+                &self.program_id,
+                source,
+                &self.pubkey,
+                delegate,
+                authority,
+                &multisig_signers,
+                amount,
+                decimals,
+                expiry_slot,
+            )?],
+            signing_keypairs,
+        )
+        .await
+    }
+
+    /// Set or clear the secondary authority of a token account
+    pub async fn configure_secondary_authority<S: Signers>(
+        &self,
+        account: &Pubkey,
+        owner: &Pubkey,
+        secondary_authority: Option<Pubkey>,
+        max_transfer_amount: u64,
+        signing_keypairs: &S,
+    ) -> TokenResult<T::Output> {
+        let signing_pubkeys = signing_keypairs.pubkeys();
+        let multisig_signers = self.get_multisig_signers(owner, &signing_pubkeys);
+
+        self.process_ixs(
+            &[secondary_authority::instruction::configure(
+                &self.program_id,
+                account,
+                owner,
+                &multisig_signers,
+                secondary_authority,
+                max_transfer_amount,
+            )?],
+            signing_keypairs,
+        )
+        .await
+    }
+
+    /// Harvest the royalties withheld for one recipient into its destination
+    pub async fn harvest_royalties(
+        &self,
+        recipient_index: u8,
+        destination: &Pubkey,
+        sources: &[&Pubkey],
+    ) -> TokenResult<T::Output> {
+        self.process_ixs::<[&dyn Signer; 0]>(
+            &[royalty::instruction::harvest(
+                &self.program_id,
+                self.get_address(),
+                destination,
+                sources,
+                recipient_index,
+            )?],
+            &[],
+        )
+        .await
+    }
+
+    /// Replace the royalty recipients, given destination and basis point pairs
+    pub async fn update_royalty_recipients<S: Signers>(
+        &self,
+        authority: &Pubkey,
+        recipients: &[(Pubkey, u16)],
+        signing_keypairs: &S,
+    ) -> TokenResult<T::Output> {
+        let signing_pubkeys = signing_keypairs.pubkeys();
+        let multisig_signers = self.get_multisig_signers(authority, &signing_pubkeys);
+
+        self.process_ixs(
+            &[royalty::instruction::update_recipients(
+                &self.program_id,
+                self.get_address(),
+                authority,
+                &multisig_signers,
+                recipients,
+            )?],
+            signing_keypairs,
+        )
+        .await
+    }
+
+    /// Freeze a token account until the given unix timestamp
+    pub async fn freeze_until<S: Signers>(
+        &self,
+        account: &Pubkey,
+        authority: &Pubkey,
+        thaw_timestamp: i64,
+        signing_keypairs: &S,
+    ) -> TokenResult<T::Output> {
+        let signing_pubkeys = signing_keypairs.pubkeys();
+        let multisig_signers = self.get_multisig_signers(authority, &signing_pubkeys);
+
+        self.process_ixs(
+            &[timed_freeze::instruction::freeze_until(
+                &self.program_id,
+                account,
+                &self.pubkey,
+                authority,
+                &multisig_signers,
+                thaw_timestamp,
+            )?],
+            signing_keypairs,
+        )
+        .await
+    }
+
+    /// Thaw a token account whose timed freeze has expired
+    pub async fn thaw_expired(&self, account: &Pubkey) -> TokenResult<T::Output> {
+        self.process_ixs::<[&dyn Signer; 0]>(
+            &[timed_freeze::instruction::thaw(&self.program_id, account)?],
+            &[],
+        )
+        .await
+    }
+
+    /// Set or clear the recovery address of a soul-bound token account
+    pub async fn set_recovery_address<S: Signers>(
+        &self,
+        account: &Pubkey,
+        owner: &Pubkey,
+        recovery_address: Option<Pubkey>,
+        signing_keypairs: &S,
+    ) -> TokenResult<T::Output> {
+        let signing_pubkeys = signing_keypairs.pubkeys();
+        let multisig_signers = self.get_multisig_signers(owner, &signing_pubkeys);
+
+        self.process_ixs(
+            &[soul_bound::instruction::set_recovery_address(
+                &self.program_id,
+                account,
+                owner,
+                &multisig_signers,
+                recovery_address,
+            )?],
+            signing_keypairs,
+        )
+        .await
+    }
+
+    /// Move the whole balance of a soul-bound token account to an account
+    /// owned by its recovery address
+    pub async fn recover_account<S: Signers>(
+        &self,
+        account: &Pubkey,
+        destination: &Pubkey,
+        owner: &Pubkey,
+        recovery_address: &Pubkey,
+        signing_keypairs: &S,
+    ) -> TokenResult<T::Output> {
+        let signing_pubkeys = signing_keypairs.pubkeys();
+        // an authority that signs directly needs no multisig signers
+        let multisig_signers = |authority: &Pubkey| {
+            if signing_pubkeys.contains(authority) {
+                vec![]
+            } else {
+                signing_pubkeys
+                    .iter()
+                    .filter(|pubkey| *pubkey != owner && *pubkey != recovery_address)
+                    .collect::<Vec<_>>()
+            }
+        };
+
+        self.process_ixs(
+            &[soul_bound::instruction::recover_account(
+                &self.program_id,
+                account,
+                &self.pubkey,
+                destination,
+                owner,
+                &multisig_signers(owner),
+                recovery_address,
+                &multisig_signers(recovery_address),
+            )?],
+            signing_keypairs,
+        )
+        .await
+    }
+
+    /// Move tokens into escrow until the destination's owner accepts them
+    pub async fn initiate_transfer<S: Signers>(
+        &self,
+        source: &Pubkey,
+        destination: &Pubkey,
+        authority: &Pubkey,
+        amount: u64,
+        signing_keypairs: &S,
+    ) -> TokenResult<T::Output> {
+        let signing_pubkeys = signing_keypairs.pubkeys();
+        let multisig_signers = self.get_multisig_signers(authority, &signing_pubkeys);
+        let decimals = self.decimals.ok_or(TokenError::MissingDecimals)?;
+
+        self.process_ixs(
+            &[pending_transfer::instruction::initiate_transfer(
+                &self.program_id,
+                source,
+                &self.pubkey,
+                destination,
+                authority,
+                &multisig_signers,
+                amount,
+                decimals,
+            )?],
+            signing_keypairs,
+        )
+        .await
+    }
+
+    /// Accept a pending transfer into the destination account
+    pub async fn accept_transfer<S: Signers>(
+        &self,
+        source: &Pubkey,
+        destination: &Pubkey,
+        authority: &Pubkey,
+        signing_keypairs: &S,
+    ) -> TokenResult<T::Output> {
+        let signing_pubkeys = signing_keypairs.pubkeys();
+        let multisig_signers = self.get_multisig_signers(authority, &signing_pubkeys);
+
+        self.process_ixs(
+            &[pending_transfer::instruction::accept_transfer(
+                &self.program_id,
+                source,
+                &self.pubkey,
+                destination,
+                authority,
+                &multisig_signers,
+            )?],
+            signing_keypairs,
+        )
+        .await
+    }
+
+    /// Cancel or reject a pending transfer, returning the tokens to the source
+    pub async fn cancel_transfer<S: Signers>(
+        &self,
+        source: &Pubkey,
+        destination: &Pubkey,
+        authority: &Pubkey,
+        signing_keypairs: &S,
+    ) -> TokenResult<T::Output> {
+        let signing_pubkeys = signing_keypairs.pubkeys();
+        let multisig_signers = self.get_multisig_signers(authority, &signing_pubkeys);
+
+        self.process_ixs(
+            &[pending_transfer::instruction::cancel_transfer(
+                &self.program_id,
+                source,
+                &self.pubkey,
+                destination,
+                authority,
+                &multisig_signers,
+            )?],
+            signing_keypairs,
+        )
+        .await
+    }
+
+    /// Pause minting, burning and transferring for the mint
+    pub async fn pause<S: Signers>(
+        &self,
+        authority: &Pubkey,
+        signing_keypairs: &S,
+    ) -> TokenResult<T::Output> {
+        let signing_pubkeys = signing_keypairs.pubkeys();
+        let multisig_signers = self.get_multisig_signers(authority, &signing_pubkeys);
+
+        self.process_ixs(
+            &[pausable::instruction::pause(
+                &self.program_id,
+                self.get_address(),
+                authority,
+                &multisig_signers,
+            )?],
+            signing_keypairs,
+        )
+        .await
+    }
+
+    /// Resume minting, burning and transferring for the mint
+    pub async fn resume<S: Signers>(
+        &self,
+        authority: &Pubkey,
+        signing_keypairs: &S,
+    ) -> TokenResult<T::Output> {
+        let signing_pubkeys = signing_keypairs.pubkeys();
+        let multisig_signers = self.get_multisig_signers(authority, &signing_pubkeys);
+
+        self.process_ixs(
+            &[pausable::instruction::resume(
+                &self.program_id,
+                self.get_address(),
+                authority,
+                &multisig_signers,
+            )?],
+            signing_keypairs,
+        )
+        .await
+    }
+
     /// Update confidential transfer mint
     pub async fn confidential_transfer_update_mint<S: Signers>(
         &self,
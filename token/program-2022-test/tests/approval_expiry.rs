@@ -0,0 +1,155 @@
+#![cfg(feature = "test-sbf")]
+
+mod program_test;
+use {
+    program_test::{custom_error, instruction_error, TestContext, TokenContext},
+    solana_program_test::tokio,
+    solana_sdk::{
+        instruction::InstructionError, pubkey::Pubkey, signature::Signer, signer::keypair::Keypair,
+    },
+    spl_token_2022::{
+        error::TokenError,
+        extension::{approval_expiry::ApprovalExpiry, BaseStateWithExtensions, ExtensionType},
+    },
+};
+
+async fn warp_to_slot(context: &TestContext, slot: u64) {
+    context.context.lock().await.warp_to_slot(slot).unwrap();
+}
+
+async fn setup() -> (TestContext, TokenContext, Pubkey, Pubkey) {
+    let mut context = TestContext::new().await;
+    context.init_token_with_mint(vec![]).await.unwrap();
+    let token_context = context.token_context.take().unwrap();
+    let TokenContext {
+        token,
+        alice,
+        bob,
+        mint_authority,
+        ..
+    } = &token_context;
+
+    let alice_account = Keypair::new();
+    token
+        .create_auxiliary_token_account(&alice_account, &alice.pubkey())
+        .await
+        .unwrap();
+    let alice_account = alice_account.pubkey();
+    let bob_account = Keypair::new();
+    token
+        .create_auxiliary_token_account(&bob_account, &bob.pubkey())
+        .await
+        .unwrap();
+    let bob_account = bob_account.pubkey();
+
+    token
+        .mint_to(
+            &alice_account,
+            &mint_authority.pubkey(),
+            1_000,
+            &[mint_authority],
+        )
+        .await
+        .unwrap();
+    (context, token_context, alice_account, bob_account)
+}
+
+#[tokio::test]
+async fn expiry() {
+    let (context, token_context, alice_account, bob_account) = setup().await;
+    let TokenContext {
+        token, alice, bob, ..
+    } = &token_context;
+
+    // fail, no room for the extension
+    let err = token
+        .approve_with_expiry(
+            &alice_account,
+            &bob.pubkey(),
+            &alice.pubkey(),
+            100,
+            2_000,
+            &[alice],
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err,
+        instruction_error(0, InstructionError::InvalidAccountData)
+    );
+
+    // success
+    token
+        .reallocate(
+            &alice_account,
+            &alice.pubkey(),
+            &[ExtensionType::ApprovalExpiry],
+            &[alice],
+        )
+        .await
+        .unwrap();
+    warp_to_slot(&context, 1_000).await;
+    token
+        .approve_with_expiry(
+            &alice_account,
+            &bob.pubkey(),
+            &alice.pubkey(),
+            100,
+            2_000,
+            &[alice],
+        )
+        .await
+        .unwrap();
+    let state = token.get_account_info(&alice_account).await.unwrap();
+    let extension = state.get_extension::<ApprovalExpiry>().unwrap();
+    assert_eq!(u64::from(extension.expiry_slot), 2_000);
+    assert_eq!(state.base.delegate, Some(bob.pubkey()).into());
+
+    // success, the delegate can act before the expiry
+    token
+        .transfer(&alice_account, &bob_account, &bob.pubkey(), 10, &[bob])
+        .await
+        .unwrap();
+    token
+        .burn(&alice_account, &bob.pubkey(), 10, &[bob])
+        .await
+        .unwrap();
+
+    // fail, the approval has expired
+    warp_to_slot(&context, 3_000).await;
+    let err = token
+        .transfer(&alice_account, &bob_account, &bob.pubkey(), 11, &[bob])
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::DelegateApprovalExpired));
+    let err = token
+        .burn(&alice_account, &bob.pubkey(), 11, &[bob])
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::DelegateApprovalExpired));
+
+    // success, the owner is not affected
+    token
+        .transfer(&alice_account, &bob_account, &alice.pubkey(), 12, &[alice])
+        .await
+        .unwrap();
+
+    // success, a plain approval clears the expiry
+    token
+        .approve(
+            &alice_account,
+            &bob.pubkey(),
+            &alice.pubkey(),
+            100,
+            &[alice],
+        )
+        .await
+        .unwrap();
+    let state = token.get_account_info(&alice_account).await.unwrap();
+    let extension = state.get_extension::<ApprovalExpiry>().unwrap();
+    assert_eq!(u64::from(extension.expiry_slot), 0);
+    token
+        .transfer(&alice_account, &bob_account, &bob.pubkey(), 13, &[bob])
+        .await
+        .unwrap();
+}
@@ -0,0 +1,392 @@
+#![cfg(feature = "test-sbf")]
+
+mod program_test;
+use {
+    program_test::{custom_error, TestContext, TokenContext},
+    solana_program_test::tokio,
+    solana_sdk::{pubkey::Pubkey, signature::Signer, signer::keypair::Keypair},
+    spl_token_2022::{
+        error::TokenError,
+        extension::{
+            distribution::{DistributionAccount, DistributionConfig},
+            snapshot::SnapshotConfig,
+            BaseStateWithExtensions,
+        },
+        instruction,
+    },
+    spl_token_client::token::ExtensionInitializationParams,
+};
+
+struct Accounts {
+    authority: Pubkey,
+    alice: Pubkey,
+    bob: Pubkey,
+}
+
+async fn setup(authority: &Keypair) -> (TokenContext, Accounts) {
+    let mut context = TestContext::new().await;
+    context
+        .init_token_with_mint(vec![
+            ExtensionInitializationParams::SnapshotConfig { authority: None },
+            ExtensionInitializationParams::DistributionConfig {
+                authority: Some(authority.pubkey()),
+            },
+        ])
+        .await
+        .unwrap();
+    let token_context = context.token_context.take().unwrap();
+    let TokenContext {
+        token,
+        alice,
+        bob,
+        mint_authority,
+        ..
+    } = &token_context;
+
+    let mut accounts = vec![];
+    for (owner, amount) in [
+        (authority.pubkey(), 100),
+        (alice.pubkey(), 300),
+        (bob.pubkey(), 600),
+    ] {
+        let account = Keypair::new();
+        token
+            .create_auxiliary_token_account(&account, &owner)
+            .await
+            .unwrap();
+        token
+            .mint_to(
+                &account.pubkey(),
+                &mint_authority.pubkey(),
+                amount,
+                &[mint_authority],
+            )
+            .await
+            .unwrap();
+        accounts.push(account.pubkey());
+    }
+    let accounts = Accounts {
+        authority: accounts[0],
+        alice: accounts[1],
+        bob: accounts[2],
+    };
+    (token_context, accounts)
+}
+
+#[tokio::test]
+async fn success_initialize() {
+    let authority = Pubkey::new_unique();
+    let mut context = TestContext::new().await;
+    context
+        .init_token_with_mint(vec![
+            ExtensionInitializationParams::SnapshotConfig { authority: None },
+            ExtensionInitializationParams::DistributionConfig {
+                authority: Some(authority),
+            },
+        ])
+        .await
+        .unwrap();
+    let TokenContext { token, alice, .. } = context.token_context.unwrap();
+
+    let state = token.get_mint_info().await.unwrap();
+    let extension = state.get_extension::<DistributionConfig>().unwrap();
+    assert_eq!(Option::<Pubkey>::from(extension.authority), Some(authority));
+    assert_eq!(u64::from(extension.distribution_id), 0);
+
+    // accounts are created with no claims
+    let account = Keypair::new();
+    token
+        .create_auxiliary_token_account(&account, &alice.pubkey())
+        .await
+        .unwrap();
+    let state = token.get_account_info(&account.pubkey()).await.unwrap();
+    let extension = state.get_extension::<DistributionAccount>().unwrap();
+    assert_eq!(*extension, DistributionAccount::default());
+}
+
+#[tokio::test]
+async fn fail_initialize_without_snapshot() {
+    let mut context = TestContext::new().await;
+    let err = context
+        .init_token_with_mint(vec![ExtensionInitializationParams::DistributionConfig {
+            authority: Some(Pubkey::new_unique()),
+        }])
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err,
+        custom_error(2, TokenError::InvalidExtensionCombination)
+    );
+}
+
+#[tokio::test]
+async fn fund_and_claim() {
+    let authority = Keypair::new();
+    let (token_context, accounts) = setup(&authority).await;
+    let TokenContext {
+        token, alice, bob, ..
+    } = &token_context;
+
+    // fail, nothing to claim yet
+    let err = token.claim_distribution(&accounts.alice).await.unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::NoActiveDistribution));
+
+    // fail, wrong signer
+    let wrong_signer = Keypair::new();
+    let err = token
+        .fund_distribution(
+            &accounts.authority,
+            &wrong_signer.pubkey(),
+            100,
+            &[&wrong_signer],
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::OwnerMismatch));
+
+    // success, 100 tokens against the 900 held by alice and bob
+    token
+        .fund_distribution(&accounts.authority, &authority.pubkey(), 100, &[&authority])
+        .await
+        .unwrap();
+    let state = token.get_mint_info().await.unwrap();
+    let extension = state.get_extension::<DistributionConfig>().unwrap();
+    assert_eq!(u64::from(extension.distribution_id), 1);
+    assert_eq!(u64::from(extension.snapshot_id), 1);
+    assert_eq!(u64::from(extension.eligible_supply), 900);
+    assert_eq!(u64::from(extension.pool_amount), 100);
+    let extension = state.get_extension::<SnapshotConfig>().unwrap();
+    assert_eq!(u64::from(extension.snapshot_id), 1);
+
+    // balance changes after the snapshot do not affect the claim
+    token
+        .transfer(&accounts.bob, &accounts.alice, &bob.pubkey(), 300, &[bob])
+        .await
+        .unwrap();
+
+    token.claim_distribution(&accounts.alice).await.unwrap();
+    let state = token.get_account_info(&accounts.alice).await.unwrap();
+    assert_eq!(u64::from(state.base.amount), 600 + 33);
+    let extension = state.get_extension::<DistributionAccount>().unwrap();
+    assert_eq!(u64::from(extension.last_claimed_distribution_id), 1);
+
+    token.claim_distribution(&accounts.bob).await.unwrap();
+    let state = token.get_account_info(&accounts.bob).await.unwrap();
+    assert_eq!(u64::from(state.base.amount), 300 + 66);
+
+    // fail, already claimed
+    let err = token.claim_distribution(&accounts.alice).await.unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::DistributionAlreadyClaimed));
+
+    // the rounding leftover stays in the pool
+    let state = token.get_mint_info().await.unwrap();
+    let extension = state.get_extension::<DistributionConfig>().unwrap();
+    assert_eq!(u64::from(extension.pool_amount), 1);
+
+    // the next distribution pays the leftover too, and alice can claim again
+    token
+        .transfer(
+            &accounts.alice,
+            &accounts.authority,
+            &alice.pubkey(),
+            50,
+            &[alice],
+        )
+        .await
+        .unwrap();
+    token
+        .fund_distribution(&accounts.authority, &authority.pubkey(), 50, &[&authority])
+        .await
+        .unwrap();
+    let state = token.get_mint_info().await.unwrap();
+    let extension = state.get_extension::<DistributionConfig>().unwrap();
+    assert_eq!(u64::from(extension.distribution_id), 2);
+    assert_eq!(u64::from(extension.total_amount), 51);
+    token.claim_distribution(&accounts.alice).await.unwrap();
+}
+
+#[tokio::test]
+async fn sweep() {
+    let authority = Keypair::new();
+    let (token_context, accounts) = setup(&authority).await;
+    let TokenContext { token, .. } = &token_context;
+
+    token
+        .fund_distribution(&accounts.authority, &authority.pubkey(), 100, &[&authority])
+        .await
+        .unwrap();
+    token.claim_distribution(&accounts.alice).await.unwrap();
+
+    // fail, wrong signer
+    let wrong_signer = Keypair::new();
+    let err = token
+        .sweep_distribution(
+            &accounts.authority,
+            &wrong_signer.pubkey(),
+            &[&wrong_signer],
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::OwnerMismatch));
+
+    // success, bob's unclaimed share goes back to the authority
+    token
+        .sweep_distribution(&accounts.authority, &authority.pubkey(), &[&authority])
+        .await
+        .unwrap();
+    let state = token.get_account_info(&accounts.authority).await.unwrap();
+    assert_eq!(u64::from(state.base.amount), 67);
+    let state = token.get_mint_info().await.unwrap();
+    let extension = state.get_extension::<DistributionConfig>().unwrap();
+    assert_eq!(u64::from(extension.distribution_id), 1);
+    assert_eq!(u64::from(extension.total_amount), 0);
+    assert_eq!(u64::from(extension.pool_amount), 0);
+
+    // fail, the distribution is closed
+    let err = token.claim_distribution(&accounts.bob).await.unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::NoActiveDistribution));
+}
+
+#[tokio::test]
+async fn fail_claim_denylisted() {
+    let authority = Keypair::new();
+    let mut context = TestContext::new().await;
+    context
+        .init_token_with_mint(vec![
+            ExtensionInitializationParams::SnapshotConfig { authority: None },
+            ExtensionInitializationParams::DistributionConfig {
+                authority: Some(authority.pubkey()),
+            },
+            ExtensionInitializationParams::TransferRestrictionConfig {
+                authority: Some(authority.pubkey()),
+                allowlist_required: false,
+            },
+        ])
+        .await
+        .unwrap();
+    let TokenContext {
+        token,
+        alice,
+        mint_authority,
+        ..
+    } = context.token_context.unwrap();
+
+    let mut accounts = vec![];
+    for (owner, amount) in [(authority.pubkey(), 100), (alice.pubkey(), 300)] {
+        let account = Keypair::new();
+        token
+            .create_auxiliary_token_account(&account, &owner)
+            .await
+            .unwrap();
+        token
+            .mint_to(
+                &account.pubkey(),
+                &mint_authority.pubkey(),
+                amount,
+                &[&mint_authority],
+            )
+            .await
+            .unwrap();
+        accounts.push(account.pubkey());
+    }
+    token
+        .fund_distribution(&accounts[0], &authority.pubkey(), 100, &[&authority])
+        .await
+        .unwrap();
+
+    token
+        .add_to_denylist(&accounts[1], &authority.pubkey(), &[&authority])
+        .await
+        .unwrap();
+    let err = token.claim_distribution(&accounts[1]).await.unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::AccountDenylisted));
+}
+
+#[tokio::test]
+async fn set_authority() {
+    let authority = Keypair::new();
+    let (token_context, accounts) = setup(&authority).await;
+    let TokenContext { token, .. } = &token_context;
+    let new_authority = Keypair::new();
+
+    // fail, wrong signature
+    let wrong = Keypair::new();
+    let err = token
+        .set_authority(
+            token.get_address(),
+            &wrong.pubkey(),
+            Some(&new_authority.pubkey()),
+            instruction::AuthorityType::Distribution,
+            &[&wrong],
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::OwnerMismatch));
+
+    // success
+    token
+        .set_authority(
+            token.get_address(),
+            &authority.pubkey(),
+            Some(&new_authority.pubkey()),
+            instruction::AuthorityType::Distribution,
+            &[&authority],
+        )
+        .await
+        .unwrap();
+    let state = token.get_mint_info().await.unwrap();
+    let extension = state.get_extension::<DistributionConfig>().unwrap();
+    assert_eq!(
+        Option::<Pubkey>::from(extension.authority),
+        Some(new_authority.pubkey()),
+    );
+
+    // fail, the source must be owned by the authority
+    let err = token
+        .fund_distribution(
+            &accounts.authority,
+            &new_authority.pubkey(),
+            10,
+            &[&new_authority],
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::OwnerMismatch));
+
+    // set to none
+    token
+        .set_authority(
+            token.get_address(),
+            &new_authority.pubkey(),
+            None,
+            instruction::AuthorityType::Distribution,
+            &[&new_authority],
+        )
+        .await
+        .unwrap();
+
+    // fail, no more distributions
+    let err = token
+        .fund_distribution(
+            &accounts.authority,
+            &new_authority.pubkey(),
+            10,
+            &[&new_authority],
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::NoAuthorityExists));
+
+    // fail set again
+    let err = token
+        .set_authority(
+            token.get_address(),
+            &new_authority.pubkey(),
+            Some(&authority.pubkey()),
+            instruction::AuthorityType::Distribution,
+            &[&new_authority],
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::AuthorityTypeNotSupported));
+}
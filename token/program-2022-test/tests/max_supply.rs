@@ -0,0 +1,277 @@
+#![cfg(feature = "test-sbf")]
+
+mod program_test;
+use {
+    program_test::{custom_error, TestContext, TokenContext},
+    solana_program_test::tokio,
+    solana_sdk::{pubkey::Pubkey, signature::Signer, signer::keypair::Keypair},
+    spl_token_2022::{
+        error::TokenError,
+        extension::{max_supply::MaxSupplyConfig, BaseStateWithExtensions},
+        instruction,
+    },
+    spl_token_client::token::ExtensionInitializationParams,
+};
+
+async fn setup_account(token_context: &TokenContext) -> Pubkey {
+    let alice_account = Keypair::new();
+    token_context
+        .token
+        .create_auxiliary_token_account(&alice_account, &token_context.alice.pubkey())
+        .await
+        .unwrap();
+    alice_account.pubkey()
+}
+
+#[tokio::test]
+async fn success_initialize() {
+    for (authority, max_supply) in [(None, 0), (Some(Pubkey::new_unique()), u64::MAX)] {
+        let mut context = TestContext::new().await;
+        context
+            .init_token_with_mint(vec![ExtensionInitializationParams::MaxSupplyConfig {
+                authority,
+                max_supply,
+            }])
+            .await
+            .unwrap();
+        let TokenContext { token, .. } = context.token_context.unwrap();
+
+        let state = token.get_mint_info().await.unwrap();
+        let extension = state.get_extension::<MaxSupplyConfig>().unwrap();
+        assert_eq!(Option::<Pubkey>::from(extension.authority), authority);
+        assert_eq!(u64::from(extension.max_supply), max_supply);
+    }
+}
+
+#[tokio::test]
+async fn mint_to() {
+    let max_supply = 100;
+    let mut context = TestContext::new().await;
+    context
+        .init_token_with_mint(vec![ExtensionInitializationParams::MaxSupplyConfig {
+            authority: None,
+            max_supply,
+        }])
+        .await
+        .unwrap();
+    let token_context = context.token_context.unwrap();
+    let alice_account = setup_account(&token_context).await;
+    let TokenContext {
+        token,
+        mint_authority,
+        alice,
+        ..
+    } = token_context;
+
+    // success, up to the cap
+    token
+        .mint_to(
+            &alice_account,
+            &mint_authority.pubkey(),
+            max_supply,
+            &[&mint_authority],
+        )
+        .await
+        .unwrap();
+    let state = token.get_mint_info().await.unwrap();
+    assert_eq!(state.base.supply, max_supply);
+
+    // fail, over the cap
+    let err = token
+        .mint_to(
+            &alice_account,
+            &mint_authority.pubkey(),
+            1,
+            &[&mint_authority],
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::MaxSupplyExceeded));
+
+    // burning frees up room under the cap
+    token
+        .burn(&alice_account, &alice.pubkey(), 1, &[&alice])
+        .await
+        .unwrap();
+    token
+        .mint_to(
+            &alice_account,
+            &mint_authority.pubkey(),
+            1,
+            &[&mint_authority],
+        )
+        .await
+        .unwrap();
+    let state = token.get_mint_info().await.unwrap();
+    assert_eq!(state.base.supply, max_supply);
+}
+
+#[tokio::test]
+async fn update_max_supply() {
+    let authority = Keypair::new();
+    let mut context = TestContext::new().await;
+    context
+        .init_token_with_mint(vec![ExtensionInitializationParams::MaxSupplyConfig {
+            authority: Some(authority.pubkey()),
+            max_supply: 100,
+        }])
+        .await
+        .unwrap();
+    let token_context = context.token_context.unwrap();
+    let alice_account = setup_account(&token_context).await;
+    let TokenContext {
+        token,
+        mint_authority,
+        ..
+    } = token_context;
+
+    token
+        .mint_to(
+            &alice_account,
+            &mint_authority.pubkey(),
+            50,
+            &[&mint_authority],
+        )
+        .await
+        .unwrap();
+
+    // fail, wrong signer
+    let wrong_signer = Keypair::new();
+    let err = token
+        .update_max_supply(&wrong_signer.pubkey(), 200, &[&wrong_signer])
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::OwnerMismatch));
+
+    // fail, below the current supply
+    let err = token
+        .update_max_supply(&authority.pubkey(), 49, &[&authority])
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::MaxSupplyExceeded));
+
+    // success, down to the current supply
+    token
+        .update_max_supply(&authority.pubkey(), 50, &[&authority])
+        .await
+        .unwrap();
+    let state = token.get_mint_info().await.unwrap();
+    let extension = state.get_extension::<MaxSupplyConfig>().unwrap();
+    assert_eq!(u64::from(extension.max_supply), 50);
+
+    let err = token
+        .mint_to(
+            &alice_account,
+            &mint_authority.pubkey(),
+            1,
+            &[&mint_authority],
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::MaxSupplyExceeded));
+
+    // success, raise the cap
+    token
+        .update_max_supply(&authority.pubkey(), 200, &[&authority])
+        .await
+        .unwrap();
+    token
+        .mint_to(
+            &alice_account,
+            &mint_authority.pubkey(),
+            150,
+            &[&mint_authority],
+        )
+        .await
+        .unwrap();
+    let state = token.get_mint_info().await.unwrap();
+    assert_eq!(state.base.supply, 200);
+}
+
+#[tokio::test]
+async fn set_authority() {
+    let authority = Keypair::new();
+    let mut context = TestContext::new().await;
+    context
+        .init_token_with_mint(vec![ExtensionInitializationParams::MaxSupplyConfig {
+            authority: Some(authority.pubkey()),
+            max_supply: 100,
+        }])
+        .await
+        .unwrap();
+    let TokenContext { token, .. } = context.token_context.unwrap();
+    let new_authority = Keypair::new();
+
+    // fail, wrong signature
+    let wrong = Keypair::new();
+    let err = token
+        .set_authority(
+            token.get_address(),
+            &wrong.pubkey(),
+            Some(&new_authority.pubkey()),
+            instruction::AuthorityType::MaxSupply,
+            &[&wrong],
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::OwnerMismatch));
+
+    // success
+    token
+        .set_authority(
+            token.get_address(),
+            &authority.pubkey(),
+            Some(&new_authority.pubkey()),
+            instruction::AuthorityType::MaxSupply,
+            &[&authority],
+        )
+        .await
+        .unwrap();
+    let state = token.get_mint_info().await.unwrap();
+    let extension = state.get_extension::<MaxSupplyConfig>().unwrap();
+    assert_eq!(
+        Option::<Pubkey>::from(extension.authority),
+        Some(new_authority.pubkey()),
+    );
+
+    // the new authority can update
+    token
+        .update_max_supply(&new_authority.pubkey(), 10, &[&new_authority])
+        .await
+        .unwrap();
+
+    // set to none
+    token
+        .set_authority(
+            token.get_address(),
+            &new_authority.pubkey(),
+            None,
+            instruction::AuthorityType::MaxSupply,
+            &[&new_authority],
+        )
+        .await
+        .unwrap();
+    let state = token.get_mint_info().await.unwrap();
+    let extension = state.get_extension::<MaxSupplyConfig>().unwrap();
+    assert_eq!(Option::<Pubkey>::from(extension.authority), None);
+
+    // fail, the cap is now fixed
+    let err = token
+        .update_max_supply(&new_authority.pubkey(), 20, &[&new_authority])
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::NoAuthorityExists));
+
+    // fail set again
+    let err = token
+        .set_authority(
+            token.get_address(),
+            &new_authority.pubkey(),
+            Some(&authority.pubkey()),
+            instruction::AuthorityType::MaxSupply,
+            &[&new_authority],
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::AuthorityTypeNotSupported));
+}
@@ -0,0 +1,253 @@
+#![cfg(feature = "test-sbf")]
+
+mod program_test;
+use {
+    program_test::{custom_error, TestContext, TokenContext},
+    solana_program_test::tokio,
+    solana_sdk::{pubkey::Pubkey, signature::Signer, signer::keypair::Keypair},
+    spl_token_2022::{
+        error::TokenError,
+        extension::{pausable::PausableConfig, BaseStateWithExtensions},
+        instruction,
+    },
+    spl_token_client::token::ExtensionInitializationParams,
+};
+
+async fn setup(authority: &Pubkey) -> (TokenContext, Pubkey, Pubkey) {
+    let mut context = TestContext::new().await;
+    context
+        .init_token_with_freezing_mint(vec![ExtensionInitializationParams::PausableConfig {
+            authority: Some(*authority),
+        }])
+        .await
+        .unwrap();
+    let token_context = context.token_context.take().unwrap();
+    let TokenContext {
+        token,
+        alice,
+        bob,
+        mint_authority,
+        ..
+    } = &token_context;
+
+    let alice_account = Keypair::new();
+    token
+        .create_auxiliary_token_account(&alice_account, &alice.pubkey())
+        .await
+        .unwrap();
+    let alice_account = alice_account.pubkey();
+    let bob_account = Keypair::new();
+    token
+        .create_auxiliary_token_account(&bob_account, &bob.pubkey())
+        .await
+        .unwrap();
+    let bob_account = bob_account.pubkey();
+
+    token
+        .mint_to(
+            &alice_account,
+            &mint_authority.pubkey(),
+            100,
+            &[mint_authority],
+        )
+        .await
+        .unwrap();
+    (token_context, alice_account, bob_account)
+}
+
+#[tokio::test]
+async fn success_initialize() {
+    for authority in [None, Some(Pubkey::new_unique())] {
+        let mut context = TestContext::new().await;
+        context
+            .init_token_with_mint(vec![ExtensionInitializationParams::PausableConfig {
+                authority,
+            }])
+            .await
+            .unwrap();
+        let TokenContext { token, .. } = context.token_context.unwrap();
+
+        let state = token.get_mint_info().await.unwrap();
+        let extension = state.get_extension::<PausableConfig>().unwrap();
+        assert_eq!(Option::<Pubkey>::from(extension.authority), authority);
+        assert!(!bool::from(extension.paused));
+    }
+}
+
+#[tokio::test]
+async fn pause_and_resume() {
+    let pause_authority = Keypair::new();
+    let (token_context, alice_account, bob_account) = setup(&pause_authority.pubkey()).await;
+    let TokenContext {
+        token,
+        alice,
+        bob,
+        mint_authority,
+        freeze_authority,
+        ..
+    } = &token_context;
+    let freeze_authority = freeze_authority.as_ref().unwrap();
+
+    // fail, only the pause authority can pause
+    let err = token.pause(&alice.pubkey(), &[alice]).await.unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::OwnerMismatch));
+
+    token
+        .freeze(
+            &bob_account,
+            &freeze_authority.pubkey(),
+            &[freeze_authority],
+        )
+        .await
+        .unwrap();
+    token
+        .pause(&pause_authority.pubkey(), &[&pause_authority])
+        .await
+        .unwrap();
+    let state = token.get_mint_info().await.unwrap();
+    let extension = state.get_extension::<PausableConfig>().unwrap();
+    assert!(bool::from(extension.paused));
+
+    // fail, minting, burning and transferring are blocked
+    let err = token
+        .mint_to(
+            &alice_account,
+            &mint_authority.pubkey(),
+            1,
+            &[mint_authority],
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::MintPaused));
+    let err = token
+        .burn(&alice_account, &alice.pubkey(), 1, &[alice])
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::MintPaused));
+    token
+        .thaw(
+            &bob_account,
+            &freeze_authority.pubkey(),
+            &[freeze_authority],
+        )
+        .await
+        .unwrap();
+    let err = token
+        .transfer(&alice_account, &bob_account, &alice.pubkey(), 1, &[alice])
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::MintPaused));
+
+    // success, empty accounts can still be closed
+    token
+        .close_account(&bob_account, &bob.pubkey(), &bob.pubkey(), &[bob])
+        .await
+        .unwrap();
+
+    token
+        .resume(&pause_authority.pubkey(), &[&pause_authority])
+        .await
+        .unwrap();
+    token
+        .burn(&alice_account, &alice.pubkey(), 1, &[alice])
+        .await
+        .unwrap();
+    let state = token.get_account_info(&alice_account).await.unwrap();
+    assert_eq!(state.base.amount, 99);
+}
+
+#[tokio::test]
+async fn set_authority() {
+    let pause_authority = Keypair::new();
+    let (token_context, _, _) = setup(&pause_authority.pubkey()).await;
+    let TokenContext { token, alice, .. } = &token_context;
+
+    // fail, wrong signer
+    let err = token
+        .set_authority(
+            token.get_address(),
+            &alice.pubkey(),
+            Some(&alice.pubkey()),
+            instruction::AuthorityType::Pause,
+            &[alice],
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::OwnerMismatch));
+
+    // success, then the new authority can pause
+    token
+        .set_authority(
+            token.get_address(),
+            &pause_authority.pubkey(),
+            Some(&alice.pubkey()),
+            instruction::AuthorityType::Pause,
+            &[&pause_authority],
+        )
+        .await
+        .unwrap();
+    token.pause(&alice.pubkey(), &[alice]).await.unwrap();
+
+    // success, remove the authority
+    token
+        .set_authority(
+            token.get_address(),
+            &alice.pubkey(),
+            None,
+            instruction::AuthorityType::Pause,
+            &[alice],
+        )
+        .await
+        .unwrap();
+    let err = token.resume(&alice.pubkey(), &[alice]).await.unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::NoAuthorityExists));
+}
+
+#[tokio::test]
+async fn fail_withdraw_fees_while_paused() {
+    let pause_authority = Keypair::new();
+    let withdraw_withheld_authority = Keypair::new();
+    let mut context = TestContext::new().await;
+    context
+        .init_token_with_mint(vec![
+            ExtensionInitializationParams::TransferFeeConfig {
+                transfer_fee_config_authority: None,
+                withdraw_withheld_authority: Some(withdraw_withheld_authority.pubkey()),
+                transfer_fee_basis_points: 100,
+                maximum_fee: 1_000,
+            },
+            ExtensionInitializationParams::PausableConfig {
+                authority: Some(pause_authority.pubkey()),
+            },
+        ])
+        .await
+        .unwrap();
+    let TokenContext { token, alice, .. } = context.token_context.unwrap();
+
+    let alice_account = Keypair::new();
+    token
+        .create_auxiliary_token_account(&alice_account, &alice.pubkey())
+        .await
+        .unwrap();
+    let alice_account = alice_account.pubkey();
+    token
+        .pause(&pause_authority.pubkey(), &[&pause_authority])
+        .await
+        .unwrap();
+
+    // fail, withheld fees cannot be moved while paused
+    let err = token
+        .harvest_withheld_tokens_to_mint(&[&alice_account])
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::MintPaused));
+    let err = token
+        .withdraw_withheld_tokens_from_mint(
+            &alice_account,
+            &withdraw_withheld_authority.pubkey(),
+            &[&withdraw_withheld_authority],
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::MintPaused));
+}
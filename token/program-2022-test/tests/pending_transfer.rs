@@ -0,0 +1,313 @@
+#![cfg(feature = "test-sbf")]
+
+mod program_test;
+use {
+    program_test::{custom_error, TestContext, TokenContext},
+    solana_program_test::tokio,
+    solana_sdk::{pubkey::Pubkey, signature::Signer, signer::keypair::Keypair},
+    spl_token_2022::{
+        error::TokenError,
+        extension::{pending_transfer::PendingTransfer, BaseStateWithExtensions, ExtensionType},
+    },
+    spl_token_client::token::ExtensionInitializationParams,
+};
+
+async fn setup(extensions: Vec<ExtensionInitializationParams>) -> (TokenContext, Pubkey, Pubkey) {
+    let mut context = TestContext::new().await;
+    context.init_token_with_mint(extensions).await.unwrap();
+    let token_context = context.token_context.take().unwrap();
+    let TokenContext {
+        token,
+        alice,
+        bob,
+        mint_authority,
+        ..
+    } = &token_context;
+
+    let alice_account = Keypair::new();
+    token
+        .create_auxiliary_token_account(&alice_account, &alice.pubkey())
+        .await
+        .unwrap();
+    let alice_account = alice_account.pubkey();
+    token
+        .reallocate(
+            &alice_account,
+            &alice.pubkey(),
+            &[ExtensionType::PendingTransfer],
+            &[alice],
+        )
+        .await
+        .unwrap();
+    let bob_account = Keypair::new();
+    token
+        .create_auxiliary_token_account(&bob_account, &bob.pubkey())
+        .await
+        .unwrap();
+    let bob_account = bob_account.pubkey();
+
+    token
+        .mint_to(
+            &alice_account,
+            &mint_authority.pubkey(),
+            1_000,
+            &[mint_authority],
+        )
+        .await
+        .unwrap();
+    (token_context, alice_account, bob_account)
+}
+
+#[tokio::test]
+async fn initiate_and_accept() {
+    let (token_context, alice_account, bob_account) = setup(vec![]).await;
+    let TokenContext {
+        token, alice, bob, ..
+    } = &token_context;
+
+    // fail, only the owner can initiate
+    let err = token
+        .initiate_transfer(&alice_account, &bob_account, &bob.pubkey(), 400, &[bob])
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::OwnerMismatch));
+
+    // success, the amount leaves the source
+    token
+        .initiate_transfer(&alice_account, &bob_account, &alice.pubkey(), 400, &[alice])
+        .await
+        .unwrap();
+    let state = token.get_account_info(&alice_account).await.unwrap();
+    assert_eq!(state.base.amount, 600);
+    let extension = state.get_extension::<PendingTransfer>().unwrap();
+    assert_eq!(
+        Option::<Pubkey>::from(extension.destination),
+        Some(bob_account)
+    );
+    assert_eq!(u64::from(extension.amount), 400);
+
+    // fail, escrowed tokens cannot be spent
+    let err = token
+        .transfer(&alice_account, &bob_account, &alice.pubkey(), 601, &[alice])
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::InsufficientFunds));
+
+    // fail, one pending transfer at a time
+    let err = token
+        .initiate_transfer(&alice_account, &bob_account, &alice.pubkey(), 1, &[alice])
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::PendingTransferExists));
+
+    // fail, only the destination's owner can accept
+    let err = token
+        .accept_transfer(&alice_account, &bob_account, &alice.pubkey(), &[alice])
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::OwnerMismatch));
+
+    // success
+    token
+        .accept_transfer(&alice_account, &bob_account, &bob.pubkey(), &[bob])
+        .await
+        .unwrap();
+    let state = token.get_account_info(&bob_account).await.unwrap();
+    assert_eq!(state.base.amount, 400);
+    let state = token.get_account_info(&alice_account).await.unwrap();
+    let extension = state.get_extension::<PendingTransfer>().unwrap();
+    assert_eq!(*extension, PendingTransfer::default());
+
+    // fail, nothing left to accept
+    let err = token
+        .accept_transfer(&alice_account, &bob_account, &bob.pubkey(), &[bob])
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::NoPendingTransfer));
+}
+
+#[tokio::test]
+async fn cancel_and_reject() {
+    let (token_context, alice_account, bob_account) = setup(vec![]).await;
+    let TokenContext {
+        token, alice, bob, ..
+    } = &token_context;
+
+    // success, the sender cancels
+    token
+        .initiate_transfer(&alice_account, &bob_account, &alice.pubkey(), 400, &[alice])
+        .await
+        .unwrap();
+    token
+        .cancel_transfer(&alice_account, &bob_account, &alice.pubkey(), &[alice])
+        .await
+        .unwrap();
+    let state = token.get_account_info(&alice_account).await.unwrap();
+    assert_eq!(state.base.amount, 1_000);
+
+    // fail, cannot close with a pending transfer
+    token
+        .initiate_transfer(
+            &alice_account,
+            &bob_account,
+            &alice.pubkey(),
+            1_000,
+            &[alice],
+        )
+        .await
+        .unwrap();
+    let err = token
+        .close_account(&alice_account, &alice.pubkey(), &alice.pubkey(), &[alice])
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::PendingTransferExists));
+
+    // success, the recipient rejects
+    token
+        .cancel_transfer(&alice_account, &bob_account, &bob.pubkey(), &[bob])
+        .await
+        .unwrap();
+    let state = token.get_account_info(&alice_account).await.unwrap();
+    assert_eq!(state.base.amount, 1_000);
+
+    // fail, a third party cannot cancel
+    token
+        .initiate_transfer(&alice_account, &bob_account, &alice.pubkey(), 400, &[alice])
+        .await
+        .unwrap();
+    let stranger = Keypair::new();
+    let err = token
+        .cancel_transfer(
+            &alice_account,
+            &bob_account,
+            &stranger.pubkey(),
+            &[&stranger],
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::OwnerMismatch));
+
+    // success, the sender can still cancel once the destination is closed
+    token
+        .close_account(&bob_account, &bob.pubkey(), &bob.pubkey(), &[bob])
+        .await
+        .unwrap();
+    token
+        .cancel_transfer(&alice_account, &bob_account, &alice.pubkey(), &[alice])
+        .await
+        .unwrap();
+    let state = token.get_account_info(&alice_account).await.unwrap();
+    assert_eq!(state.base.amount, 1_000);
+}
+
+#[tokio::test]
+async fn accept_requires_memo() {
+    let (token_context, alice_account, bob_account) = setup(vec![]).await;
+    let TokenContext {
+        token, alice, bob, ..
+    } = &token_context;
+
+    token
+        .reallocate(
+            &bob_account,
+            &bob.pubkey(),
+            &[ExtensionType::MemoTransfer],
+            &[bob],
+        )
+        .await
+        .unwrap();
+    token
+        .enable_required_transfer_memos(&bob_account, &bob.pubkey(), &[bob])
+        .await
+        .unwrap();
+    token
+        .initiate_transfer(&alice_account, &bob_account, &alice.pubkey(), 400, &[alice])
+        .await
+        .unwrap();
+
+    let err = token
+        .accept_transfer(&alice_account, &bob_account, &bob.pubkey(), &[bob])
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::NoMemo));
+}
+
+#[tokio::test]
+async fn fail_with_transfer_fee() {
+    let (token_context, alice_account, bob_account) =
+        setup(vec![ExtensionInitializationParams::TransferFeeConfig {
+            transfer_fee_config_authority: None,
+            withdraw_withheld_authority: None,
+            transfer_fee_basis_points: 100,
+            maximum_fee: 1_000,
+        }])
+        .await;
+    let TokenContext { token, alice, .. } = &token_context;
+
+    let err = token
+        .initiate_transfer(&alice_account, &bob_account, &alice.pubkey(), 400, &[alice])
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err,
+        custom_error(0, TokenError::EscrowedTransferNotSupported)
+    );
+}
+
+#[tokio::test]
+async fn fail_with_snapshot() {
+    let (token_context, alice_account, bob_account) =
+        setup(vec![ExtensionInitializationParams::SnapshotConfig {
+            authority: None,
+        }])
+        .await;
+    let TokenContext { token, alice, .. } = &token_context;
+
+    let err = token
+        .initiate_transfer(&alice_account, &bob_account, &alice.pubkey(), 400, &[alice])
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err,
+        custom_error(0, TokenError::EscrowedTransferNotSupported)
+    );
+}
+
+#[tokio::test]
+async fn accept_rechecks_source_restriction() {
+    let authority = Keypair::new();
+    let (token_context, alice_account, bob_account) = setup(vec![
+        ExtensionInitializationParams::TransferRestrictionConfig {
+            authority: Some(authority.pubkey()),
+            allowlist_required: false,
+        },
+    ])
+    .await;
+    let TokenContext {
+        token, alice, bob, ..
+    } = &token_context;
+
+    token
+        .initiate_transfer(&alice_account, &bob_account, &alice.pubkey(), 400, &[alice])
+        .await
+        .unwrap();
+    token
+        .add_to_denylist(&alice_account, &authority.pubkey(), &[&authority])
+        .await
+        .unwrap();
+
+    // fail, the source was denylisted after initiating
+    let err = token
+        .accept_transfer(&alice_account, &bob_account, &bob.pubkey(), &[bob])
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::AccountDenylisted));
+
+    // the sender can still take the tokens back
+    token
+        .cancel_transfer(&alice_account, &bob_account, &alice.pubkey(), &[alice])
+        .await
+        .unwrap();
+    let state = token.get_account_info(&alice_account).await.unwrap();
+    assert_eq!(state.base.amount, 1_000);
+}
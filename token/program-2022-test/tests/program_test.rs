@@ -3,10 +3,14 @@
 use {
     solana_program_test::{processor, tokio::sync::Mutex, ProgramTest, ProgramTestContext},
     solana_sdk::{
+        instruction::InstructionError,
         pubkey::Pubkey,
         signer::{keypair::Keypair, Signer},
+        transaction::TransactionError,
+        transport::TransportError,
     },
     spl_token_2022::{
+        error::TokenError,
         extension::{
             confidential_transfer::ConfidentialTransferAccount, BaseStateWithExtensions,
             ExtensionType,
@@ -20,11 +24,26 @@ use {
             ProgramBanksClient, ProgramBanksClientProcessTransaction, ProgramClient,
             SendTransaction, SimulateTransaction,
         },
-        token::{ExtensionInitializationParams, Token, TokenResult},
+        token::{
+            ExtensionInitializationParams, Token, TokenError as TokenClientError, TokenResult,
+        },
     },
     std::sync::Arc,
 };
 
+/// Client error for the instruction at `index` failing with `error`
+pub fn instruction_error(index: u8, error: InstructionError) -> TokenClientError {
+    TokenClientError::Client(Box::new(TransportError::TransactionError(
+        TransactionError::InstructionError(index, error),
+    )))
+}
+
+/// Client error for the instruction at `index` failing with a custom token
+/// error
+pub fn custom_error(index: u8, error: TokenError) -> TokenClientError {
+    instruction_error(index, InstructionError::Custom(error as u32))
+}
+
 pub struct TokenContext {
     pub decimals: u8,
     pub mint_authority: Keypair,
@@ -0,0 +1,432 @@
+#![cfg(feature = "test-sbf")]
+
+mod program_test;
+use {
+    program_test::{custom_error, TestContext, TokenContext},
+    solana_program_test::tokio,
+    solana_sdk::{pubkey::Pubkey, signature::Signer, signer::keypair::Keypair},
+    spl_token_2022::{
+        error::TokenError,
+        extension::{
+            royalty::{RoyaltyAccount, RoyaltyConfig},
+            BaseStateWithExtensions,
+        },
+        instruction,
+    },
+    spl_token_client::token::ExtensionInitializationParams,
+};
+
+struct Accounts {
+    first_recipient: Pubkey,
+    second_recipient: Pubkey,
+    alice: Pubkey,
+    bob: Pubkey,
+}
+
+async fn setup(authority: &Keypair) -> (TokenContext, Accounts) {
+    let first_recipient = Keypair::new();
+    let second_recipient = Keypair::new();
+    let mut context = TestContext::new().await;
+    context
+        .init_token_with_mint(vec![ExtensionInitializationParams::RoyaltyConfig {
+            authority: Some(authority.pubkey()),
+            recipients: vec![
+                (first_recipient.pubkey(), 100),
+                (second_recipient.pubkey(), 250),
+            ],
+        }])
+        .await
+        .unwrap();
+    let token_context = context.token_context.take().unwrap();
+    let TokenContext {
+        token,
+        alice,
+        bob,
+        mint_authority,
+        ..
+    } = &token_context;
+
+    let alice_account = Keypair::new();
+    let bob_account = Keypair::new();
+    for (account, owner) in [
+        (&first_recipient, bob.pubkey()),
+        (&second_recipient, bob.pubkey()),
+        (&alice_account, alice.pubkey()),
+        (&bob_account, bob.pubkey()),
+    ] {
+        token
+            .create_auxiliary_token_account(account, &owner)
+            .await
+            .unwrap();
+    }
+    token
+        .mint_to(
+            &alice_account.pubkey(),
+            &mint_authority.pubkey(),
+            10_000,
+            &[mint_authority],
+        )
+        .await
+        .unwrap();
+
+    let accounts = Accounts {
+        first_recipient: first_recipient.pubkey(),
+        second_recipient: second_recipient.pubkey(),
+        alice: alice_account.pubkey(),
+        bob: bob_account.pubkey(),
+    };
+    (token_context, accounts)
+}
+
+#[tokio::test]
+async fn success_initialize() {
+    let authority = Keypair::new();
+    let (token_context, accounts) = setup(&authority).await;
+    let TokenContext { token, .. } = &token_context;
+
+    let state = token.get_mint_info().await.unwrap();
+    let extension = state.get_extension::<RoyaltyConfig>().unwrap();
+    assert_eq!(
+        Option::<Pubkey>::from(extension.authority),
+        Some(authority.pubkey())
+    );
+    assert_eq!(extension.recipient_count, 2);
+    assert_eq!(
+        extension.recipients()[0].destination,
+        accounts.first_recipient
+    );
+    assert_eq!(u16::from(extension.recipients()[1].basis_points), 250);
+
+    let state = token.get_account_info(&accounts.bob).await.unwrap();
+    let extension = state.get_extension::<RoyaltyAccount>().unwrap();
+    assert_eq!(*extension, RoyaltyAccount::default());
+}
+
+#[tokio::test]
+async fn fail_initialize_over_maximum() {
+    let mut context = TestContext::new().await;
+    let err = context
+        .init_token_with_mint(vec![ExtensionInitializationParams::RoyaltyConfig {
+            authority: None,
+            recipients: vec![(Pubkey::new_unique(), 5_000), (Pubkey::new_unique(), 5_001)],
+        }])
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(1, TokenError::InvalidRoyaltyRecipients));
+}
+
+#[tokio::test]
+async fn fail_initialize_over_maximum_with_transfer_fee() {
+    let mut context = TestContext::new().await;
+    let err = context
+        .init_token_with_mint(vec![
+            ExtensionInitializationParams::TransferFeeConfig {
+                transfer_fee_config_authority: None,
+                withdraw_withheld_authority: None,
+                transfer_fee_basis_points: 9_000,
+                maximum_fee: u64::MAX,
+            },
+            ExtensionInitializationParams::RoyaltyConfig {
+                authority: None,
+                recipients: vec![(Pubkey::new_unique(), 1_001)],
+            },
+        ])
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err,
+        custom_error(3, TokenError::RoyaltyAndTransferFeeExceedMaximum)
+    );
+}
+
+#[tokio::test]
+async fn withhold_and_harvest() {
+    let (token_context, accounts) = setup(&Keypair::new()).await;
+    let TokenContext {
+        token, alice, bob, ..
+    } = &token_context;
+
+    // success, royalties are withheld in the destination
+    token
+        .transfer(
+            &accounts.alice,
+            &accounts.bob,
+            &alice.pubkey(),
+            1_000,
+            &[alice],
+        )
+        .await
+        .unwrap();
+    let state = token.get_account_info(&accounts.bob).await.unwrap();
+    assert_eq!(state.base.amount, 965);
+    let extension = state.get_extension::<RoyaltyAccount>().unwrap();
+    assert_eq!(u64::from(extension.withheld_amounts[0]), 10);
+    assert_eq!(u64::from(extension.withheld_amounts[1]), 25);
+
+    // fail, cannot close with withheld royalties
+    token
+        .transfer(&accounts.bob, &accounts.alice, &bob.pubkey(), 965, &[bob])
+        .await
+        .unwrap();
+    let err = token
+        .close_account(&accounts.bob, &bob.pubkey(), &bob.pubkey(), &[bob])
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err,
+        custom_error(0, TokenError::AccountHasWithheldTransferFees)
+    );
+
+    // fail, wrong destination for the recipient
+    let err = token
+        .harvest_royalties(0, &accounts.second_recipient, &[&accounts.bob])
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::RoyaltyRecipientMismatch));
+
+    // fail, no such recipient
+    let err = token
+        .harvest_royalties(2, &accounts.first_recipient, &[&accounts.bob])
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::RoyaltyRecipientMismatch));
+
+    // success, each recipient harvests its own share
+    token
+        .harvest_royalties(
+            0,
+            &accounts.first_recipient,
+            &[&accounts.bob, &accounts.alice],
+        )
+        .await
+        .unwrap();
+    let state = token
+        .get_account_info(&accounts.first_recipient)
+        .await
+        .unwrap();
+    // alice's account withheld 9 + 24 from the transfer back
+    assert_eq!(state.base.amount, 10 + 9);
+    let state = token.get_account_info(&accounts.bob).await.unwrap();
+    let extension = state.get_extension::<RoyaltyAccount>().unwrap();
+    assert_eq!(u64::from(extension.withheld_amounts[0]), 0);
+    assert_eq!(u64::from(extension.withheld_amounts[1]), 25);
+
+    token
+        .harvest_royalties(
+            1,
+            &accounts.second_recipient,
+            &[&accounts.bob, &accounts.alice],
+        )
+        .await
+        .unwrap();
+    let state = token
+        .get_account_info(&accounts.second_recipient)
+        .await
+        .unwrap();
+    assert_eq!(state.base.amount, 25 + 24);
+
+    // success, nothing left to harvest
+    token
+        .close_account(&accounts.bob, &bob.pubkey(), &bob.pubkey(), &[bob])
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn update_recipients() {
+    let authority = Keypair::new();
+    let (token_context, accounts) = setup(&authority).await;
+    let TokenContext { token, alice, .. } = &token_context;
+
+    token
+        .transfer(
+            &accounts.alice,
+            &accounts.bob,
+            &alice.pubkey(),
+            1_000,
+            &[alice],
+        )
+        .await
+        .unwrap();
+
+    // fail, wrong signer
+    let wrong_signer = Keypair::new();
+    let err = token
+        .update_royalty_recipients(
+            &wrong_signer.pubkey(),
+            &[(accounts.alice, 100)],
+            &[&wrong_signer],
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::OwnerMismatch));
+
+    // fail, over the maximum
+    let err = token
+        .update_royalty_recipients(
+            &authority.pubkey(),
+            &[(accounts.alice, 10_000), (accounts.second_recipient, 1)],
+            &[&authority],
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::InvalidRoyaltyRecipients));
+
+    // fail, the second recipient still has royalties withheld
+    let err = token
+        .update_royalty_recipients(&authority.pubkey(), &[(accounts.alice, 100)], &[&authority])
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::InvalidRoyaltyRecipients));
+
+    // success, the first recipient's withheld royalties now go to alice
+    token
+        .update_royalty_recipients(
+            &authority.pubkey(),
+            &[(accounts.alice, 100), (accounts.second_recipient, 0)],
+            &[&authority],
+        )
+        .await
+        .unwrap();
+    let state = token.get_mint_info().await.unwrap();
+    let extension = state.get_extension::<RoyaltyConfig>().unwrap();
+    assert_eq!(extension.recipients()[0].destination, accounts.alice);
+    assert_eq!(u16::from(extension.recipients()[1].basis_points), 0);
+
+    let err = token
+        .harvest_royalties(0, &accounts.first_recipient, &[&accounts.bob])
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::RoyaltyRecipientMismatch));
+    token
+        .harvest_royalties(0, &accounts.alice, &[&accounts.bob])
+        .await
+        .unwrap();
+    let state = token.get_account_info(&accounts.alice).await.unwrap();
+    assert_eq!(state.base.amount, 9_000 + 10);
+}
+
+#[tokio::test]
+async fn fail_over_maximum_with_transfer_fee() {
+    let authority = Keypair::new();
+    let mut context = TestContext::new().await;
+    context
+        .init_token_with_mint(vec![
+            ExtensionInitializationParams::TransferFeeConfig {
+                transfer_fee_config_authority: Some(authority.pubkey()),
+                withdraw_withheld_authority: None,
+                transfer_fee_basis_points: 9_000,
+                maximum_fee: u64::MAX,
+            },
+            ExtensionInitializationParams::RoyaltyConfig {
+                authority: Some(authority.pubkey()),
+                recipients: vec![(Pubkey::new_unique(), 1_000)],
+            },
+        ])
+        .await
+        .unwrap();
+    let TokenContext { token, .. } = context.token_context.unwrap();
+
+    let err = token
+        .update_royalty_recipients(
+            &authority.pubkey(),
+            &[(Pubkey::new_unique(), 1_001)],
+            &[&authority],
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err,
+        custom_error(0, TokenError::RoyaltyAndTransferFeeExceedMaximum)
+    );
+
+    let err = token
+        .set_transfer_fee(&authority.pubkey(), 9_001, u64::MAX, &[&authority])
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err,
+        custom_error(0, TokenError::RoyaltyAndTransferFeeExceedMaximum)
+    );
+
+    // success, lowering the fee still counts the current one until it expires
+    token
+        .set_transfer_fee(&authority.pubkey(), 8_000, u64::MAX, &[&authority])
+        .await
+        .unwrap();
+    let err = token
+        .update_royalty_recipients(
+            &authority.pubkey(),
+            &[(Pubkey::new_unique(), 1_001)],
+            &[&authority],
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err,
+        custom_error(0, TokenError::RoyaltyAndTransferFeeExceedMaximum)
+    );
+}
+
+#[tokio::test]
+async fn set_authority() {
+    let authority = Keypair::new();
+    let (token_context, accounts) = setup(&authority).await;
+    let TokenContext { token, .. } = &token_context;
+    let new_authority = Keypair::new();
+
+    // fail, wrong signature
+    let wrong = Keypair::new();
+    let err = token
+        .set_authority(
+            token.get_address(),
+            &wrong.pubkey(),
+            Some(&new_authority.pubkey()),
+            instruction::AuthorityType::Royalty,
+            &[&wrong],
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::OwnerMismatch));
+
+    // success
+    token
+        .set_authority(
+            token.get_address(),
+            &authority.pubkey(),
+            Some(&new_authority.pubkey()),
+            instruction::AuthorityType::Royalty,
+            &[&authority],
+        )
+        .await
+        .unwrap();
+    let state = token.get_mint_info().await.unwrap();
+    let extension = state.get_extension::<RoyaltyConfig>().unwrap();
+    assert_eq!(
+        Option::<Pubkey>::from(extension.authority),
+        Some(new_authority.pubkey()),
+    );
+
+    // set to none
+    token
+        .set_authority(
+            token.get_address(),
+            &new_authority.pubkey(),
+            None,
+            instruction::AuthorityType::Royalty,
+            &[&new_authority],
+        )
+        .await
+        .unwrap();
+
+    // fail, the recipients are fixed
+    let err = token
+        .update_royalty_recipients(
+            &new_authority.pubkey(),
+            &[(accounts.alice, 100)],
+            &[&new_authority],
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::NoAuthorityExists));
+}
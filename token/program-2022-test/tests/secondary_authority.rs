@@ -0,0 +1,260 @@
+#![cfg(feature = "test-sbf")]
+
+mod program_test;
+use {
+    program_test::{custom_error, instruction_error, TestContext, TokenContext},
+    solana_program_test::tokio,
+    solana_sdk::{
+        instruction::InstructionError, pubkey::Pubkey, signature::Signer, signer::keypair::Keypair,
+    },
+    spl_token_2022::{
+        error::TokenError,
+        extension::{
+            secondary_authority::SecondaryAuthority, BaseStateWithExtensions, ExtensionType,
+        },
+        instruction::AuthorityType,
+    },
+};
+
+async fn setup() -> (TokenContext, Pubkey, Pubkey) {
+    let mut context = TestContext::new().await;
+    context.init_token_with_mint(vec![]).await.unwrap();
+    let token_context = context.token_context.take().unwrap();
+    let TokenContext {
+        token,
+        alice,
+        bob,
+        mint_authority,
+        ..
+    } = &token_context;
+
+    let alice_account = Keypair::new();
+    token
+        .create_auxiliary_token_account(&alice_account, &alice.pubkey())
+        .await
+        .unwrap();
+    let alice_account = alice_account.pubkey();
+    let bob_account = Keypair::new();
+    token
+        .create_auxiliary_token_account(&bob_account, &bob.pubkey())
+        .await
+        .unwrap();
+    let bob_account = bob_account.pubkey();
+
+    token
+        .mint_to(
+            &alice_account,
+            &mint_authority.pubkey(),
+            1_000,
+            &[mint_authority],
+        )
+        .await
+        .unwrap();
+    (token_context, alice_account, bob_account)
+}
+
+#[tokio::test]
+async fn configure() {
+    let (token_context, alice_account, _) = setup().await;
+    let TokenContext { token, alice, .. } = &token_context;
+    let session_key = Keypair::new();
+
+    // fail, no room for the extension
+    let err = token
+        .configure_secondary_authority(
+            &alice_account,
+            &alice.pubkey(),
+            Some(session_key.pubkey()),
+            100,
+            &[alice],
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err,
+        instruction_error(0, InstructionError::InvalidAccountData)
+    );
+
+    token
+        .reallocate(
+            &alice_account,
+            &alice.pubkey(),
+            &[ExtensionType::SecondaryAuthority],
+            &[alice],
+        )
+        .await
+        .unwrap();
+
+    // fail, only the owner can configure
+    let err = token
+        .configure_secondary_authority(
+            &alice_account,
+            &session_key.pubkey(),
+            Some(session_key.pubkey()),
+            100,
+            &[&session_key],
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::OwnerMismatch));
+
+    // success
+    token
+        .configure_secondary_authority(
+            &alice_account,
+            &alice.pubkey(),
+            Some(session_key.pubkey()),
+            100,
+            &[alice],
+        )
+        .await
+        .unwrap();
+    let state = token.get_account_info(&alice_account).await.unwrap();
+    let extension = state.get_extension::<SecondaryAuthority>().unwrap();
+    assert_eq!(
+        Option::<Pubkey>::from(extension.authority),
+        Some(session_key.pubkey())
+    );
+    assert_eq!(u64::from(extension.max_transfer_amount), 100);
+}
+
+#[tokio::test]
+async fn limited_transfers() {
+    let (token_context, alice_account, bob_account) = setup().await;
+    let TokenContext { token, alice, .. } = &token_context;
+    let session_key = Keypair::new();
+
+    token
+        .reallocate(
+            &alice_account,
+            &alice.pubkey(),
+            &[ExtensionType::SecondaryAuthority],
+            &[alice],
+        )
+        .await
+        .unwrap();
+    token
+        .configure_secondary_authority(
+            &alice_account,
+            &alice.pubkey(),
+            Some(session_key.pubkey()),
+            100,
+            &[alice],
+        )
+        .await
+        .unwrap();
+
+    // success, up to the limit
+    token
+        .transfer(
+            &alice_account,
+            &bob_account,
+            &session_key.pubkey(),
+            100,
+            &[&session_key],
+        )
+        .await
+        .unwrap();
+
+    // fail, over the limit
+    let err = token
+        .transfer(
+            &alice_account,
+            &bob_account,
+            &session_key.pubkey(),
+            101,
+            &[&session_key],
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err,
+        custom_error(0, TokenError::SecondaryAuthorityLimitExceeded)
+    );
+
+    // fail, the secondary authority cannot burn
+    let err = token
+        .burn(&alice_account, &session_key.pubkey(), 1, &[&session_key])
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::OwnerMismatch));
+
+    // success, the owner is not limited
+    token
+        .transfer(&alice_account, &bob_account, &alice.pubkey(), 500, &[alice])
+        .await
+        .unwrap();
+
+    // fail, cleared by the owner
+    token
+        .configure_secondary_authority(&alice_account, &alice.pubkey(), None, 0, &[alice])
+        .await
+        .unwrap();
+    let err = token
+        .transfer(
+            &alice_account,
+            &bob_account,
+            &session_key.pubkey(),
+            1,
+            &[&session_key],
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::OwnerMismatch));
+}
+
+#[tokio::test]
+async fn cleared_on_owner_change() {
+    let (token_context, alice_account, bob_account) = setup().await;
+    let TokenContext {
+        token, alice, bob, ..
+    } = &token_context;
+    let session_key = Keypair::new();
+
+    token
+        .reallocate(
+            &alice_account,
+            &alice.pubkey(),
+            &[ExtensionType::SecondaryAuthority],
+            &[alice],
+        )
+        .await
+        .unwrap();
+    token
+        .configure_secondary_authority(
+            &alice_account,
+            &alice.pubkey(),
+            Some(session_key.pubkey()),
+            100,
+            &[alice],
+        )
+        .await
+        .unwrap();
+
+    token
+        .set_authority(
+            &alice_account,
+            &alice.pubkey(),
+            Some(&bob.pubkey()),
+            AuthorityType::AccountOwner,
+            &[alice],
+        )
+        .await
+        .unwrap();
+    let state = token.get_account_info(&alice_account).await.unwrap();
+    let extension = state.get_extension::<SecondaryAuthority>().unwrap();
+    assert_eq!(*extension, SecondaryAuthority::default());
+
+    // fail, the old secondary authority no longer signs for the account
+    let err = token
+        .transfer(
+            &alice_account,
+            &bob_account,
+            &session_key.pubkey(),
+            1,
+            &[&session_key],
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::OwnerMismatch));
+}
@@ -0,0 +1,302 @@
+#![cfg(feature = "test-sbf")]
+
+mod program_test;
+use {
+    program_test::{custom_error, TestContext, TokenContext},
+    solana_program_test::tokio,
+    solana_sdk::{
+        instruction::InstructionError,
+        pubkey::Pubkey,
+        signature::Signer,
+        signer::keypair::Keypair,
+        transaction::{Transaction, TransactionError},
+    },
+    spl_token_2022::{
+        error::TokenError,
+        extension::{
+            snapshot::{self, SnapshotAccount, SnapshotConfig},
+            BaseStateWithExtensions,
+        },
+        instruction,
+    },
+    spl_token_client::token::ExtensionInitializationParams,
+    std::convert::TryInto,
+};
+
+async fn setup(authority: &Keypair) -> (TestContext, TokenContext, Pubkey, Pubkey) {
+    let mut context = TestContext::new().await;
+    context
+        .init_token_with_mint(vec![ExtensionInitializationParams::SnapshotConfig {
+            authority: Some(authority.pubkey()),
+        }])
+        .await
+        .unwrap();
+    let token_context = context.token_context.take().unwrap();
+
+    let alice_account = Keypair::new();
+    token_context
+        .token
+        .create_auxiliary_token_account(&alice_account, &token_context.alice.pubkey())
+        .await
+        .unwrap();
+    let alice_account = alice_account.pubkey();
+    let bob_account = Keypair::new();
+    token_context
+        .token
+        .create_auxiliary_token_account(&bob_account, &token_context.bob.pubkey())
+        .await
+        .unwrap();
+    let bob_account = bob_account.pubkey();
+    (context, token_context, alice_account, bob_account)
+}
+
+async fn balance_at(
+    context: &TestContext,
+    token_context: &TokenContext,
+    account: &Pubkey,
+    snapshot_id: u64,
+) -> Result<u64, TransactionError> {
+    let mut context = context.context.lock().await;
+    let transaction = Transaction::new_signed_with_payer(
+        &[snapshot::instruction::get_balance_at(
+            &spl_token_2022::id(),
+            account,
+            token_context.token.get_address(),
+            snapshot_id,
+        )
+        .unwrap()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let simulation = context
+        .banks_client
+        .simulate_transaction(transaction)
+        .await
+        .unwrap();
+    simulation.result.unwrap()?;
+    let return_data = simulation.simulation_details.unwrap().return_data.unwrap();
+    Ok(u64::from_le_bytes(
+        return_data.data[..8].try_into().unwrap(),
+    ))
+}
+
+#[tokio::test]
+async fn success_initialize() {
+    let authority = Pubkey::new_unique();
+    let mut context = TestContext::new().await;
+    context
+        .init_token_with_mint(vec![ExtensionInitializationParams::SnapshotConfig {
+            authority: Some(authority),
+        }])
+        .await
+        .unwrap();
+    let TokenContext { token, alice, .. } = context.token_context.unwrap();
+
+    let state = token.get_mint_info().await.unwrap();
+    let extension = state.get_extension::<SnapshotConfig>().unwrap();
+    assert_eq!(Option::<Pubkey>::from(extension.authority), Some(authority));
+    assert_eq!(u64::from(extension.snapshot_id), 0);
+
+    // accounts are created with no checkpoints
+    let account = Keypair::new();
+    token
+        .create_auxiliary_token_account(&account, &alice.pubkey())
+        .await
+        .unwrap();
+    let state = token.get_account_info(&account.pubkey()).await.unwrap();
+    let extension = state.get_extension::<SnapshotAccount>().unwrap();
+    assert_eq!(*extension, SnapshotAccount::default());
+}
+
+#[tokio::test]
+async fn create_snapshot() {
+    let authority = Keypair::new();
+    let (_context, token_context, _, _) = setup(&authority).await;
+    let TokenContext { token, .. } = &token_context;
+
+    // fail, wrong signer
+    let wrong_signer = Keypair::new();
+    let err = token
+        .create_snapshot(&wrong_signer.pubkey(), &[&wrong_signer])
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::OwnerMismatch));
+
+    // success
+    token
+        .create_snapshot(&authority.pubkey(), &[&authority])
+        .await
+        .unwrap();
+    let state = token.get_mint_info().await.unwrap();
+    let extension = state.get_extension::<SnapshotConfig>().unwrap();
+    assert_eq!(u64::from(extension.snapshot_id), 1);
+}
+
+#[tokio::test]
+async fn checkpoints() {
+    let authority = Keypair::new();
+    let (context, token_context, alice_account, bob_account) = setup(&authority).await;
+    let TokenContext {
+        token,
+        alice,
+        mint_authority,
+        ..
+    } = &token_context;
+
+    // balances before the first snapshot are not checkpointed
+    token
+        .mint_to(
+            &alice_account,
+            &mint_authority.pubkey(),
+            100,
+            &[mint_authority],
+        )
+        .await
+        .unwrap();
+    let state = token.get_account_info(&alice_account).await.unwrap();
+    let extension = state.get_extension::<SnapshotAccount>().unwrap();
+    assert_eq!(extension.checkpoint_count, 0);
+
+    // snapshot 1: alice 100, bob 0
+    token
+        .create_snapshot(&authority.pubkey(), &[&authority])
+        .await
+        .unwrap();
+    token
+        .transfer(&alice_account, &bob_account, &alice.pubkey(), 30, &[alice])
+        .await
+        .unwrap();
+    token
+        .mint_to(
+            &bob_account,
+            &mint_authority.pubkey(),
+            10,
+            &[mint_authority],
+        )
+        .await
+        .unwrap();
+
+    // snapshot 2: alice 70, bob 40
+    token
+        .create_snapshot(&authority.pubkey(), &[&authority])
+        .await
+        .unwrap();
+    token
+        .burn(&alice_account, &alice.pubkey(), 20, &[alice])
+        .await
+        .unwrap();
+
+    let state = token.get_account_info(&alice_account).await.unwrap();
+    let extension = state.get_extension::<SnapshotAccount>().unwrap();
+    assert_eq!(extension.checkpoint_count, 2);
+    assert_eq!(u64::from(state.base.amount), 50);
+
+    assert_eq!(
+        balance_at(&context, &token_context, &alice_account, 1)
+            .await
+            .unwrap(),
+        100
+    );
+    assert_eq!(
+        balance_at(&context, &token_context, &alice_account, 2)
+            .await
+            .unwrap(),
+        70
+    );
+    assert_eq!(
+        balance_at(&context, &token_context, &bob_account, 1)
+            .await
+            .unwrap(),
+        0
+    );
+    assert_eq!(
+        balance_at(&context, &token_context, &bob_account, 2)
+            .await
+            .unwrap(),
+        40
+    );
+
+    // fail, snapshot does not exist yet
+    assert_eq!(
+        balance_at(&context, &token_context, &alice_account, 3)
+            .await
+            .unwrap_err(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(TokenError::SnapshotNotAvailable as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn set_authority() {
+    let authority = Keypair::new();
+    let (_context, token_context, _, _) = setup(&authority).await;
+    let TokenContext { token, .. } = &token_context;
+    let new_authority = Keypair::new();
+
+    // fail, wrong signature
+    let wrong = Keypair::new();
+    let err = token
+        .set_authority(
+            token.get_address(),
+            &wrong.pubkey(),
+            Some(&new_authority.pubkey()),
+            instruction::AuthorityType::Snapshot,
+            &[&wrong],
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::OwnerMismatch));
+
+    // success
+    token
+        .set_authority(
+            token.get_address(),
+            &authority.pubkey(),
+            Some(&new_authority.pubkey()),
+            instruction::AuthorityType::Snapshot,
+            &[&authority],
+        )
+        .await
+        .unwrap();
+    let state = token.get_mint_info().await.unwrap();
+    let extension = state.get_extension::<SnapshotConfig>().unwrap();
+    assert_eq!(
+        Option::<Pubkey>::from(extension.authority),
+        Some(new_authority.pubkey()),
+    );
+
+    // set to none
+    token
+        .set_authority(
+            token.get_address(),
+            &new_authority.pubkey(),
+            None,
+            instruction::AuthorityType::Snapshot,
+            &[&new_authority],
+        )
+        .await
+        .unwrap();
+
+    // fail, no more snapshots
+    let err = token
+        .create_snapshot(&new_authority.pubkey(), &[&new_authority])
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::NoAuthorityExists));
+
+    // fail set again
+    let err = token
+        .set_authority(
+            token.get_address(),
+            &new_authority.pubkey(),
+            Some(&authority.pubkey()),
+            instruction::AuthorityType::Snapshot,
+            &[&new_authority],
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::AuthorityTypeNotSupported));
+}
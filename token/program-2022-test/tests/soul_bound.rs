@@ -0,0 +1,267 @@
+#![cfg(feature = "test-sbf")]
+
+mod program_test;
+use {
+    program_test::{custom_error, TestContext, TokenContext},
+    solana_program_test::tokio,
+    solana_sdk::{pubkey::Pubkey, signature::Signer, signer::keypair::Keypair},
+    spl_token_2022::{
+        error::TokenError,
+        extension::{soul_bound::SoulBoundAccount, BaseStateWithExtensions, ExtensionType},
+    },
+    spl_token_client::token::ExtensionInitializationParams,
+};
+
+async fn setup() -> (TokenContext, Pubkey, Pubkey) {
+    let mut context = TestContext::new().await;
+    context
+        .init_token_with_mint(vec![ExtensionInitializationParams::SoulBound])
+        .await
+        .unwrap();
+    let token_context = context.token_context.take().unwrap();
+    let TokenContext {
+        token,
+        alice,
+        bob,
+        mint_authority,
+        ..
+    } = &token_context;
+
+    let alice_account = Keypair::new();
+    token
+        .create_auxiliary_token_account(&alice_account, &alice.pubkey())
+        .await
+        .unwrap();
+    let alice_account = alice_account.pubkey();
+    let bob_account = Keypair::new();
+    token
+        .create_auxiliary_token_account(&bob_account, &bob.pubkey())
+        .await
+        .unwrap();
+    let bob_account = bob_account.pubkey();
+
+    token
+        .mint_to(
+            &alice_account,
+            &mint_authority.pubkey(),
+            100,
+            &[mint_authority],
+        )
+        .await
+        .unwrap();
+    (token_context, alice_account, bob_account)
+}
+
+#[tokio::test]
+async fn success_initialize() {
+    let (token_context, alice_account, _) = setup().await;
+    let TokenContext { token, .. } = &token_context;
+
+    let state = token.get_account_info(&alice_account).await.unwrap();
+    let extension = state.get_extension::<SoulBoundAccount>().unwrap();
+    assert_eq!(Option::<Pubkey>::from(extension.recovery_address), None);
+    assert!(state
+        .get_extension_types()
+        .unwrap()
+        .contains(&ExtensionType::ImmutableOwner));
+}
+
+#[tokio::test]
+async fn fail_initialize_with_non_transferable() {
+    let mut context = TestContext::new().await;
+    let err = context
+        .init_token_with_mint(vec![
+            ExtensionInitializationParams::SoulBound,
+            ExtensionInitializationParams::NonTransferable,
+        ])
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err,
+        custom_error(3, TokenError::InvalidExtensionCombination)
+    );
+}
+
+#[tokio::test]
+async fn fail_initialize_with_transfer_extensions() {
+    for extension in [
+        ExtensionInitializationParams::TransferFeeConfig {
+            transfer_fee_config_authority: None,
+            withdraw_withheld_authority: None,
+            transfer_fee_basis_points: 100,
+            maximum_fee: 1_000,
+        },
+        ExtensionInitializationParams::TransferHook {
+            authority: None,
+            program_id: Some(Pubkey::new_unique()),
+        },
+        ExtensionInitializationParams::TransferLimitConfig {
+            authority: None,
+            max_per_transfer: 100,
+            daily_cap: 1_000,
+        },
+        ExtensionInitializationParams::TransferRestrictionConfig {
+            authority: None,
+            allowlist_required: false,
+        },
+    ] {
+        let mut context = TestContext::new().await;
+        let err = context
+            .init_token_with_mint(vec![ExtensionInitializationParams::SoulBound, extension])
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err,
+            custom_error(3, TokenError::InvalidExtensionCombination)
+        );
+    }
+}
+
+#[tokio::test]
+async fn recover() {
+    let (token_context, alice_account, bob_account) = setup().await;
+    let TokenContext {
+        token, alice, bob, ..
+    } = &token_context;
+
+    // fail, transfers are blocked
+    let err = token
+        .transfer(&alice_account, &bob_account, &alice.pubkey(), 1, &[alice])
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::NonTransferable));
+
+    // fail, no recovery address
+    let err = token
+        .recover_account(
+            &alice_account,
+            &bob_account,
+            &alice.pubkey(),
+            &bob.pubkey(),
+            &[alice, bob],
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::RecoveryAddressMismatch));
+
+    // fail, only the owner can set the recovery address
+    let err = token
+        .set_recovery_address(&alice_account, &bob.pubkey(), Some(bob.pubkey()), &[bob])
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::OwnerMismatch));
+
+    token
+        .set_recovery_address(
+            &alice_account,
+            &alice.pubkey(),
+            Some(bob.pubkey()),
+            &[alice],
+        )
+        .await
+        .unwrap();
+
+    // fail, destination not owned by the recovery address
+    let other_account = Keypair::new();
+    token
+        .create_auxiliary_token_account(&other_account, &alice.pubkey())
+        .await
+        .unwrap();
+    let err = token
+        .recover_account(
+            &alice_account,
+            &other_account.pubkey(),
+            &alice.pubkey(),
+            &bob.pubkey(),
+            &[alice, bob],
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::RecoveryAddressMismatch));
+
+    // fail, the recovery address must sign as itself
+    let err = token
+        .recover_account(
+            &alice_account,
+            &bob_account,
+            &alice.pubkey(),
+            &alice.pubkey(),
+            &[alice],
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::OwnerMismatch));
+
+    // success
+    token
+        .recover_account(
+            &alice_account,
+            &bob_account,
+            &alice.pubkey(),
+            &bob.pubkey(),
+            &[alice, bob],
+        )
+        .await
+        .unwrap();
+    let state = token.get_account_info(&alice_account).await.unwrap();
+    assert_eq!(state.base.amount, 0);
+    let state = token.get_account_info(&bob_account).await.unwrap();
+    assert_eq!(state.base.amount, 100);
+}
+
+#[tokio::test]
+async fn recover_multisig_owner() {
+    let (token_context, _, bob_account) = setup().await;
+    let TokenContext {
+        token,
+        alice,
+        bob,
+        mint_authority,
+        ..
+    } = &token_context;
+
+    let multisig = Keypair::new();
+    token
+        .create_multisig(&multisig, &[&alice.pubkey(), &bob.pubkey()], 1)
+        .await
+        .unwrap();
+    let multisig_account = Keypair::new();
+    token
+        .create_auxiliary_token_account(&multisig_account, &multisig.pubkey())
+        .await
+        .unwrap();
+    let multisig_account = multisig_account.pubkey();
+    token
+        .mint_to(
+            &multisig_account,
+            &mint_authority.pubkey(),
+            100,
+            &[mint_authority],
+        )
+        .await
+        .unwrap();
+    token
+        .set_recovery_address(
+            &multisig_account,
+            &multisig.pubkey(),
+            Some(bob.pubkey()),
+            &[alice],
+        )
+        .await
+        .unwrap();
+
+    token
+        .recover_account(
+            &multisig_account,
+            &bob_account,
+            &multisig.pubkey(),
+            &bob.pubkey(),
+            &[alice, bob],
+        )
+        .await
+        .unwrap();
+    let state = token.get_account_info(&multisig_account).await.unwrap();
+    assert_eq!(state.base.amount, 0);
+    let state = token.get_account_info(&bob_account).await.unwrap();
+    assert_eq!(state.base.amount, 100);
+}
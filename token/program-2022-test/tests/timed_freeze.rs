@@ -0,0 +1,169 @@
+#![cfg(feature = "test-sbf")]
+
+mod program_test;
+use {
+    program_test::{custom_error, instruction_error, TestContext, TokenContext},
+    solana_program_test::tokio,
+    solana_sdk::{
+        clock::Clock, instruction::InstructionError, pubkey::Pubkey, signature::Signer,
+        signer::keypair::Keypair,
+    },
+    spl_token_2022::{
+        error::TokenError,
+        extension::{timed_freeze::TimedFreeze, BaseStateWithExtensions, ExtensionType},
+        state::AccountState,
+    },
+};
+
+async fn set_unix_timestamp(context: &TestContext, unix_timestamp: i64) {
+    let mut context = context.context.lock().await;
+    let mut clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+    clock.unix_timestamp = unix_timestamp;
+    context.set_sysvar(&clock);
+}
+
+async fn setup() -> (TestContext, TokenContext, Pubkey) {
+    let mut context = TestContext::new().await;
+    context.init_token_with_freezing_mint(vec![]).await.unwrap();
+    let token_context = context.token_context.take().unwrap();
+    let TokenContext { token, alice, .. } = &token_context;
+
+    let alice_account = Keypair::new();
+    token
+        .create_auxiliary_token_account(&alice_account, &alice.pubkey())
+        .await
+        .unwrap();
+    let alice_account = alice_account.pubkey();
+    (context, token_context, alice_account)
+}
+
+#[tokio::test]
+async fn freeze_until() {
+    let (context, token_context, alice_account) = setup().await;
+    let TokenContext {
+        token,
+        alice,
+        freeze_authority,
+        ..
+    } = &token_context;
+    let freeze_authority = freeze_authority.as_ref().unwrap();
+    set_unix_timestamp(&context, 1_000).await;
+
+    // fail, no room for the extension
+    let err = token
+        .freeze_until(
+            &alice_account,
+            &freeze_authority.pubkey(),
+            2_000,
+            &[freeze_authority],
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err,
+        instruction_error(0, InstructionError::InvalidAccountData)
+    );
+
+    token
+        .reallocate(
+            &alice_account,
+            &alice.pubkey(),
+            &[ExtensionType::TimedFreeze],
+            &[alice],
+        )
+        .await
+        .unwrap();
+
+    // fail, only the freeze authority can freeze
+    let err = token
+        .freeze_until(&alice_account, &alice.pubkey(), 2_000, &[alice])
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::OwnerMismatch));
+
+    // success
+    token
+        .freeze_until(
+            &alice_account,
+            &freeze_authority.pubkey(),
+            2_000,
+            &[freeze_authority],
+        )
+        .await
+        .unwrap();
+    let state = token.get_account_info(&alice_account).await.unwrap();
+    assert_eq!(state.base.state, AccountState::Frozen);
+    let extension = state.get_extension::<TimedFreeze>().unwrap();
+    assert_eq!(i64::from(extension.thaw_timestamp), 2_000);
+
+    // fail, not expired yet
+    let err = token.thaw_expired(&alice_account).await.unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::TimedFreezeNotExpired));
+
+    // success, anyone can thaw once expired
+    set_unix_timestamp(&context, 2_000).await;
+    token.thaw_expired(&alice_account).await.unwrap();
+    let state = token.get_account_info(&alice_account).await.unwrap();
+    assert_eq!(state.base.state, AccountState::Initialized);
+    let extension = state.get_extension::<TimedFreeze>().unwrap();
+    assert_eq!(i64::from(extension.thaw_timestamp), 0);
+
+    // fail, not frozen
+    let err = token.thaw_expired(&alice_account).await.unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::InvalidState));
+}
+
+#[tokio::test]
+async fn plain_freeze_clears_timestamp() {
+    let (context, token_context, alice_account) = setup().await;
+    let TokenContext {
+        token,
+        alice,
+        freeze_authority,
+        ..
+    } = &token_context;
+    let freeze_authority = freeze_authority.as_ref().unwrap();
+    set_unix_timestamp(&context, 1_000).await;
+
+    token
+        .reallocate(
+            &alice_account,
+            &alice.pubkey(),
+            &[ExtensionType::TimedFreeze],
+            &[alice],
+        )
+        .await
+        .unwrap();
+    token
+        .freeze_until(
+            &alice_account,
+            &freeze_authority.pubkey(),
+            2_000,
+            &[freeze_authority],
+        )
+        .await
+        .unwrap();
+
+    // success, the authority can thaw early
+    token
+        .thaw(
+            &alice_account,
+            &freeze_authority.pubkey(),
+            &[freeze_authority],
+        )
+        .await
+        .unwrap();
+
+    // fail, a later plain freeze is not thawed by the old timestamp
+    token
+        .freeze(
+            &alice_account,
+            &freeze_authority.pubkey(),
+            &[freeze_authority],
+        )
+        .await
+        .unwrap();
+    set_unix_timestamp(&context, 3_000).await;
+    let err = token.thaw_expired(&alice_account).await.unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::TimedFreezeNotExpired));
+}
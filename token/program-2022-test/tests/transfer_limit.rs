@@ -0,0 +1,304 @@
+#![cfg(feature = "test-sbf")]
+
+mod program_test;
+use {
+    program_test::{custom_error, TestContext, TokenContext},
+    solana_program_test::tokio,
+    solana_sdk::{clock::Clock, pubkey::Pubkey, signature::Signer, signer::keypair::Keypair},
+    spl_token_2022::{
+        error::TokenError,
+        extension::{
+            transfer_limit::{TransferLimitAccount, TransferLimitConfig, SECONDS_PER_DAY},
+            BaseStateWithExtensions,
+        },
+        instruction,
+    },
+    spl_token_client::token::ExtensionInitializationParams,
+};
+
+const MAX_PER_TRANSFER: u64 = 100;
+const DAILY_CAP: u64 = 250;
+
+async fn set_unix_timestamp(context: &TestContext, unix_timestamp: i64) {
+    let mut context = context.context.lock().await;
+    let mut clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+    clock.unix_timestamp = unix_timestamp;
+    context.set_sysvar(&clock);
+}
+
+async fn setup(authority: &Keypair) -> (TestContext, TokenContext, Pubkey, Pubkey) {
+    let mut context = TestContext::new().await;
+    context
+        .init_token_with_mint(vec![ExtensionInitializationParams::TransferLimitConfig {
+            authority: Some(authority.pubkey()),
+            max_per_transfer: MAX_PER_TRANSFER,
+            daily_cap: DAILY_CAP,
+        }])
+        .await
+        .unwrap();
+    let token_context = context.token_context.take().unwrap();
+
+    let alice_account = Keypair::new();
+    token_context
+        .token
+        .create_auxiliary_token_account(&alice_account, &token_context.alice.pubkey())
+        .await
+        .unwrap();
+    let alice_account = alice_account.pubkey();
+    let bob_account = Keypair::new();
+    token_context
+        .token
+        .create_auxiliary_token_account(&bob_account, &token_context.bob.pubkey())
+        .await
+        .unwrap();
+    let bob_account = bob_account.pubkey();
+
+    token_context
+        .token
+        .mint_to(
+            &alice_account,
+            &token_context.mint_authority.pubkey(),
+            1_000,
+            &[&token_context.mint_authority],
+        )
+        .await
+        .unwrap();
+    (context, token_context, alice_account, bob_account)
+}
+
+#[tokio::test]
+async fn success_initialize() {
+    let authority = Pubkey::new_unique();
+    let mut context = TestContext::new().await;
+    context
+        .init_token_with_mint(vec![ExtensionInitializationParams::TransferLimitConfig {
+            authority: Some(authority),
+            max_per_transfer: MAX_PER_TRANSFER,
+            daily_cap: DAILY_CAP,
+        }])
+        .await
+        .unwrap();
+    let TokenContext { token, alice, .. } = context.token_context.unwrap();
+
+    let state = token.get_mint_info().await.unwrap();
+    let extension = state.get_extension::<TransferLimitConfig>().unwrap();
+    assert_eq!(Option::<Pubkey>::from(extension.authority), Some(authority));
+    assert_eq!(u64::from(extension.max_per_transfer), MAX_PER_TRANSFER);
+    assert_eq!(u64::from(extension.daily_cap), DAILY_CAP);
+
+    // accounts are created with an empty usage window
+    let account = Keypair::new();
+    token
+        .create_auxiliary_token_account(&account, &alice.pubkey())
+        .await
+        .unwrap();
+    let state = token.get_account_info(&account.pubkey()).await.unwrap();
+    let extension = state.get_extension::<TransferLimitAccount>().unwrap();
+    assert_eq!(*extension, TransferLimitAccount::default());
+}
+
+#[tokio::test]
+async fn limits() {
+    let authority = Keypair::new();
+    let (context, token_context, alice_account, bob_account) = setup(&authority).await;
+    let TokenContext { token, alice, .. } = &token_context;
+
+    let start = 1_000_000;
+    set_unix_timestamp(&context, start).await;
+
+    // fail, over the per-transfer limit
+    let err = token
+        .transfer(
+            &alice_account,
+            &bob_account,
+            &alice.pubkey(),
+            MAX_PER_TRANSFER + 1,
+            &[alice],
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::TransferLimitExceeded));
+
+    // success, up to the daily cap
+    for amount in [100, 99, 51] {
+        token
+            .transfer(
+                &alice_account,
+                &bob_account,
+                &alice.pubkey(),
+                amount,
+                &[alice],
+            )
+            .await
+            .unwrap();
+    }
+    let state = token.get_account_info(&alice_account).await.unwrap();
+    let extension = state.get_extension::<TransferLimitAccount>().unwrap();
+    assert_eq!(i64::from(extension.window_start), start);
+    assert_eq!(u64::from(extension.window_amount), DAILY_CAP);
+
+    // fail, over the daily cap
+    let err = token
+        .transfer(&alice_account, &bob_account, &alice.pubkey(), 1, &[alice])
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::DailyTransferLimitExceeded));
+
+    // the destination's window is untouched
+    let state = token.get_account_info(&bob_account).await.unwrap();
+    let extension = state.get_extension::<TransferLimitAccount>().unwrap();
+    assert_eq!(u64::from(extension.window_amount), 0);
+
+    // success, a new window opens the next day
+    set_unix_timestamp(&context, start + SECONDS_PER_DAY).await;
+    token
+        .transfer(&alice_account, &bob_account, &alice.pubkey(), 2, &[alice])
+        .await
+        .unwrap();
+    let state = token.get_account_info(&alice_account).await.unwrap();
+    let extension = state.get_extension::<TransferLimitAccount>().unwrap();
+    assert_eq!(i64::from(extension.window_start), start + SECONDS_PER_DAY);
+    assert_eq!(u64::from(extension.window_amount), 2);
+}
+
+#[tokio::test]
+async fn update_limits() {
+    let authority = Keypair::new();
+    let (_context, token_context, alice_account, bob_account) = setup(&authority).await;
+    let TokenContext { token, alice, .. } = &token_context;
+
+    // fail, wrong signer
+    let wrong_signer = Keypair::new();
+    let err = token
+        .update_transfer_limits(&wrong_signer.pubkey(), 500, 1_000, &[&wrong_signer])
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::OwnerMismatch));
+
+    // success
+    token
+        .update_transfer_limits(&authority.pubkey(), 500, 1_000, &[&authority])
+        .await
+        .unwrap();
+    let state = token.get_mint_info().await.unwrap();
+    let extension = state.get_extension::<TransferLimitConfig>().unwrap();
+    assert_eq!(u64::from(extension.max_per_transfer), 500);
+    assert_eq!(u64::from(extension.daily_cap), 1_000);
+
+    token
+        .transfer(&alice_account, &bob_account, &alice.pubkey(), 500, &[alice])
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn exemption() {
+    let authority = Keypair::new();
+    let (_context, token_context, alice_account, bob_account) = setup(&authority).await;
+    let TokenContext { token, alice, .. } = &token_context;
+
+    // fail, wrong signer
+    let wrong_signer = Keypair::new();
+    let err = token
+        .add_transfer_limit_exemption(&alice_account, &wrong_signer.pubkey(), &[&wrong_signer])
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::OwnerMismatch));
+
+    // success, exempt accounts are not limited
+    token
+        .add_transfer_limit_exemption(&alice_account, &authority.pubkey(), &[&authority])
+        .await
+        .unwrap();
+    token
+        .transfer(&alice_account, &bob_account, &alice.pubkey(), 500, &[alice])
+        .await
+        .unwrap();
+    let state = token.get_account_info(&alice_account).await.unwrap();
+    let extension = state.get_extension::<TransferLimitAccount>().unwrap();
+    assert!(bool::from(extension.exempt));
+    assert_eq!(u64::from(extension.window_amount), 0);
+
+    // fail, limited again
+    token
+        .remove_transfer_limit_exemption(&alice_account, &authority.pubkey(), &[&authority])
+        .await
+        .unwrap();
+    let err = token
+        .transfer(&alice_account, &bob_account, &alice.pubkey(), 499, &[alice])
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::TransferLimitExceeded));
+}
+
+#[tokio::test]
+async fn set_authority() {
+    let authority = Keypair::new();
+    let (_context, token_context, _, _) = setup(&authority).await;
+    let TokenContext { token, .. } = &token_context;
+    let new_authority = Keypair::new();
+
+    // fail, wrong signature
+    let wrong = Keypair::new();
+    let err = token
+        .set_authority(
+            token.get_address(),
+            &wrong.pubkey(),
+            Some(&new_authority.pubkey()),
+            instruction::AuthorityType::TransferLimit,
+            &[&wrong],
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::OwnerMismatch));
+
+    // success
+    token
+        .set_authority(
+            token.get_address(),
+            &authority.pubkey(),
+            Some(&new_authority.pubkey()),
+            instruction::AuthorityType::TransferLimit,
+            &[&authority],
+        )
+        .await
+        .unwrap();
+    let state = token.get_mint_info().await.unwrap();
+    let extension = state.get_extension::<TransferLimitConfig>().unwrap();
+    assert_eq!(
+        Option::<Pubkey>::from(extension.authority),
+        Some(new_authority.pubkey()),
+    );
+
+    // set to none
+    token
+        .set_authority(
+            token.get_address(),
+            &new_authority.pubkey(),
+            None,
+            instruction::AuthorityType::TransferLimit,
+            &[&new_authority],
+        )
+        .await
+        .unwrap();
+
+    // fail, the limits are now fixed
+    let err = token
+        .update_transfer_limits(&new_authority.pubkey(), 1, 1, &[&new_authority])
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::NoAuthorityExists));
+
+    // fail set again
+    let err = token
+        .set_authority(
+            token.get_address(),
+            &new_authority.pubkey(),
+            Some(&authority.pubkey()),
+            instruction::AuthorityType::TransferLimit,
+            &[&new_authority],
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::AuthorityTypeNotSupported));
+}
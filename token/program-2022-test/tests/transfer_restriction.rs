@@ -0,0 +1,281 @@
+#![cfg(feature = "test-sbf")]
+
+mod program_test;
+use {
+    program_test::{custom_error, TestContext, TokenContext},
+    solana_program_test::tokio,
+    solana_sdk::{pubkey::Pubkey, signature::Signer, signer::keypair::Keypair},
+    spl_token_2022::{
+        error::TokenError,
+        extension::{
+            transfer_restriction::{TransferRestriction, TransferRestrictionConfig},
+            BaseStateWithExtensions,
+        },
+        instruction,
+    },
+    spl_token_client::token::ExtensionInitializationParams,
+};
+
+async fn setup(authority: &Keypair, allowlist_required: bool) -> (TokenContext, Pubkey, Pubkey) {
+    let mut context = TestContext::new().await;
+    context
+        .init_token_with_mint(vec![
+            ExtensionInitializationParams::TransferRestrictionConfig {
+                authority: Some(authority.pubkey()),
+                allowlist_required,
+            },
+        ])
+        .await
+        .unwrap();
+    let token_context = context.token_context.take().unwrap();
+
+    let alice_account = Keypair::new();
+    token_context
+        .token
+        .create_auxiliary_token_account(&alice_account, &token_context.alice.pubkey())
+        .await
+        .unwrap();
+    let alice_account = alice_account.pubkey();
+    let bob_account = Keypair::new();
+    token_context
+        .token
+        .create_auxiliary_token_account(&bob_account, &token_context.bob.pubkey())
+        .await
+        .unwrap();
+    let bob_account = bob_account.pubkey();
+
+    // minting is not restricted
+    token_context
+        .token
+        .mint_to(
+            &alice_account,
+            &token_context.mint_authority.pubkey(),
+            1_000,
+            &[&token_context.mint_authority],
+        )
+        .await
+        .unwrap();
+    (token_context, alice_account, bob_account)
+}
+
+#[tokio::test]
+async fn success_initialize() {
+    for (authority, allowlist_required) in [(None, false), (Some(Pubkey::new_unique()), true)] {
+        let mut context = TestContext::new().await;
+        context
+            .init_token_with_mint(vec![
+                ExtensionInitializationParams::TransferRestrictionConfig {
+                    authority,
+                    allowlist_required,
+                },
+            ])
+            .await
+            .unwrap();
+        let TokenContext { token, alice, .. } = context.token_context.unwrap();
+
+        let state = token.get_mint_info().await.unwrap();
+        let extension = state.get_extension::<TransferRestrictionConfig>().unwrap();
+        assert_eq!(Option::<Pubkey>::from(extension.authority), authority);
+        assert_eq!(bool::from(extension.allowlist_required), allowlist_required);
+
+        // accounts are created with the extension, on neither list
+        let account = Keypair::new();
+        token
+            .create_auxiliary_token_account(&account, &alice.pubkey())
+            .await
+            .unwrap();
+        let state = token.get_account_info(&account.pubkey()).await.unwrap();
+        let extension = state.get_extension::<TransferRestriction>().unwrap();
+        assert_eq!(*extension, TransferRestriction::default());
+    }
+}
+
+#[tokio::test]
+async fn allowlist() {
+    let authority = Keypair::new();
+    let (token_context, alice_account, bob_account) = setup(&authority, true).await;
+    let TokenContext { token, alice, .. } = &token_context;
+
+    // fail, neither account allowlisted
+    let err = token
+        .transfer(&alice_account, &bob_account, &alice.pubkey(), 1, &[alice])
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::AccountNotAllowlisted));
+
+    // fail, wrong signer
+    let wrong_signer = Keypair::new();
+    let err = token
+        .add_to_allowlist(&alice_account, &wrong_signer.pubkey(), &[&wrong_signer])
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::OwnerMismatch));
+
+    // fail, destination not allowlisted
+    token
+        .add_to_allowlist(&alice_account, &authority.pubkey(), &[&authority])
+        .await
+        .unwrap();
+    let err = token
+        .transfer(&alice_account, &bob_account, &alice.pubkey(), 2, &[alice])
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::AccountNotAllowlisted));
+
+    // success, both allowlisted
+    token
+        .add_to_allowlist(&bob_account, &authority.pubkey(), &[&authority])
+        .await
+        .unwrap();
+    let state = token.get_account_info(&bob_account).await.unwrap();
+    let extension = state.get_extension::<TransferRestriction>().unwrap();
+    assert!(bool::from(extension.allowlisted));
+    token
+        .transfer(&alice_account, &bob_account, &alice.pubkey(), 3, &[alice])
+        .await
+        .unwrap();
+
+    // fail, destination removed from the allowlist
+    token
+        .remove_from_allowlist(&bob_account, &authority.pubkey(), &[&authority])
+        .await
+        .unwrap();
+    let err = token
+        .transfer(&alice_account, &bob_account, &alice.pubkey(), 4, &[alice])
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::AccountNotAllowlisted));
+}
+
+#[tokio::test]
+async fn denylist() {
+    let authority = Keypair::new();
+    let (token_context, alice_account, bob_account) = setup(&authority, false).await;
+    let TokenContext {
+        token, alice, bob, ..
+    } = &token_context;
+
+    // success, no lists enforced
+    token
+        .transfer(&alice_account, &bob_account, &alice.pubkey(), 10, &[alice])
+        .await
+        .unwrap();
+
+    // fail, destination denylisted
+    token
+        .add_to_denylist(&bob_account, &authority.pubkey(), &[&authority])
+        .await
+        .unwrap();
+    let err = token
+        .transfer(&alice_account, &bob_account, &alice.pubkey(), 11, &[alice])
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::AccountDenylisted));
+
+    // fail, source denylisted
+    let err = token
+        .transfer(&bob_account, &alice_account, &bob.pubkey(), 1, &[bob])
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::AccountDenylisted));
+
+    // the denylist wins over the allowlist
+    token
+        .add_to_allowlist(&bob_account, &authority.pubkey(), &[&authority])
+        .await
+        .unwrap();
+    let err = token
+        .transfer(&alice_account, &bob_account, &alice.pubkey(), 12, &[alice])
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::AccountDenylisted));
+
+    // success, removed from the denylist
+    token
+        .remove_from_denylist(&bob_account, &authority.pubkey(), &[&authority])
+        .await
+        .unwrap();
+    token
+        .transfer(&bob_account, &alice_account, &bob.pubkey(), 2, &[bob])
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn set_authority() {
+    let authority = Keypair::new();
+    let (token_context, alice_account, _) = setup(&authority, false).await;
+    let TokenContext { token, .. } = &token_context;
+    let new_authority = Keypair::new();
+
+    // fail, wrong signature
+    let wrong = Keypair::new();
+    let err = token
+        .set_authority(
+            token.get_address(),
+            &wrong.pubkey(),
+            Some(&new_authority.pubkey()),
+            instruction::AuthorityType::TransferRestriction,
+            &[&wrong],
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::OwnerMismatch));
+
+    // success
+    token
+        .set_authority(
+            token.get_address(),
+            &authority.pubkey(),
+            Some(&new_authority.pubkey()),
+            instruction::AuthorityType::TransferRestriction,
+            &[&authority],
+        )
+        .await
+        .unwrap();
+    let state = token.get_mint_info().await.unwrap();
+    let extension = state.get_extension::<TransferRestrictionConfig>().unwrap();
+    assert_eq!(
+        Option::<Pubkey>::from(extension.authority),
+        Some(new_authority.pubkey()),
+    );
+
+    // the old authority cannot update the lists anymore
+    let err = token
+        .add_to_denylist(&alice_account, &authority.pubkey(), &[&authority])
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::OwnerMismatch));
+
+    // set to none
+    token
+        .set_authority(
+            token.get_address(),
+            &new_authority.pubkey(),
+            None,
+            instruction::AuthorityType::TransferRestriction,
+            &[&new_authority],
+        )
+        .await
+        .unwrap();
+
+    // fail, the lists are now fixed
+    let err = token
+        .add_to_denylist(&alice_account, &new_authority.pubkey(), &[&new_authority])
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::NoAuthorityExists));
+
+    // fail set again
+    let err = token
+        .set_authority(
+            token.get_address(),
+            &new_authority.pubkey(),
+            Some(&authority.pubkey()),
+            instruction::AuthorityType::TransferRestriction,
+            &[&new_authority],
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::AuthorityTypeNotSupported));
+}
@@ -0,0 +1,370 @@
+#![cfg(feature = "test-sbf")]
+
+mod program_test;
+use {
+    program_test::{custom_error, instruction_error, TestContext, TokenContext},
+    solana_program_test::tokio,
+    solana_sdk::{
+        clock::Clock, instruction::InstructionError, pubkey::Pubkey, signature::Signer,
+        signer::keypair::Keypair,
+    },
+    spl_token_2022::{
+        error::TokenError,
+        extension::{
+            vesting::{VestingAccount, VestingConfig},
+            BaseStateWithExtensions, ExtensionType,
+        },
+        instruction,
+    },
+    spl_token_client::token::ExtensionInitializationParams,
+};
+
+async fn set_unix_timestamp(context: &TestContext, unix_timestamp: i64) {
+    let mut context = context.context.lock().await;
+    let mut clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+    clock.unix_timestamp = unix_timestamp;
+    context.set_sysvar(&clock);
+}
+
+async fn setup_accounts(token_context: &TokenContext, amount: u64) -> (Pubkey, Pubkey) {
+    let TokenContext {
+        token,
+        alice,
+        bob,
+        mint_authority,
+        ..
+    } = token_context;
+
+    let alice_account = Keypair::new();
+    token
+        .create_auxiliary_token_account(&alice_account, &alice.pubkey())
+        .await
+        .unwrap();
+    let alice_account = alice_account.pubkey();
+    token
+        .reallocate(
+            &alice_account,
+            &alice.pubkey(),
+            &[ExtensionType::VestingAccount],
+            &[alice],
+        )
+        .await
+        .unwrap();
+
+    let bob_account = Keypair::new();
+    token
+        .create_auxiliary_token_account(&bob_account, &bob.pubkey())
+        .await
+        .unwrap();
+    let bob_account = bob_account.pubkey();
+
+    token
+        .mint_to(
+            &alice_account,
+            &mint_authority.pubkey(),
+            amount,
+            &[mint_authority],
+        )
+        .await
+        .unwrap();
+    (alice_account, bob_account)
+}
+
+#[tokio::test]
+async fn success_initialize() {
+    for authority in [None, Some(Pubkey::new_unique())] {
+        let mut context = TestContext::new().await;
+        context
+            .init_token_with_mint(vec![ExtensionInitializationParams::VestingConfig {
+                authority,
+            }])
+            .await
+            .unwrap();
+        let TokenContext { token, .. } = context.token_context.unwrap();
+
+        let state = token.get_mint_info().await.unwrap();
+        let extension = state.get_extension::<VestingConfig>().unwrap();
+        assert_eq!(Option::<Pubkey>::from(extension.authority), authority);
+    }
+}
+
+#[tokio::test]
+async fn create_schedule() {
+    let authority = Keypair::new();
+    let mut context = TestContext::new().await;
+    context
+        .init_token_with_mint(vec![ExtensionInitializationParams::VestingConfig {
+            authority: Some(authority.pubkey()),
+        }])
+        .await
+        .unwrap();
+    let token_context = context.token_context.take().unwrap();
+    let (alice_account, bob_account) = setup_accounts(&token_context, 1_000).await;
+    let TokenContext { token, .. } = &token_context;
+
+    // fail, wrong signer
+    let wrong_signer = Keypair::new();
+    let err = token
+        .create_vesting_schedule(
+            &alice_account,
+            &wrong_signer.pubkey(),
+            1_000,
+            1_000,
+            1_500,
+            2_000,
+            &[&wrong_signer],
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::OwnerMismatch));
+
+    // fail, cliff before start
+    let err = token
+        .create_vesting_schedule(
+            &alice_account,
+            &authority.pubkey(),
+            1_000,
+            1_000,
+            500,
+            2_000,
+            &[&authority],
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::InvalidVestingSchedule));
+
+    // fail, no room for the extension
+    let err = token
+        .create_vesting_schedule(
+            &bob_account,
+            &authority.pubkey(),
+            1_000,
+            1_000,
+            1_500,
+            2_000,
+            &[&authority],
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err,
+        instruction_error(0, InstructionError::InvalidAccountData)
+    );
+
+    // success
+    token
+        .create_vesting_schedule(
+            &alice_account,
+            &authority.pubkey(),
+            1_000,
+            1_000,
+            1_500,
+            2_000,
+            &[&authority],
+        )
+        .await
+        .unwrap();
+    let state = token.get_account_info(&alice_account).await.unwrap();
+    let extension = state.get_extension::<VestingAccount>().unwrap();
+    assert_eq!(u64::from(extension.total_amount), 1_000);
+    assert_eq!(i64::from(extension.start_timestamp), 1_000);
+    assert_eq!(i64::from(extension.cliff_timestamp), 1_500);
+    assert_eq!(i64::from(extension.end_timestamp), 2_000);
+
+    // success, revoke
+    token
+        .revoke_vesting_schedule(&alice_account, &authority.pubkey(), &[&authority])
+        .await
+        .unwrap();
+    let state = token.get_account_info(&alice_account).await.unwrap();
+    let extension = state.get_extension::<VestingAccount>().unwrap();
+    assert_eq!(*extension, VestingAccount::default());
+
+    // fail, no authority
+    let mut context = TestContext::new().await;
+    context
+        .init_token_with_mint(vec![ExtensionInitializationParams::VestingConfig {
+            authority: None,
+        }])
+        .await
+        .unwrap();
+    let token_context = context.token_context.take().unwrap();
+    let (alice_account, _) = setup_accounts(&token_context, 1_000).await;
+    let err = token_context
+        .token
+        .revoke_vesting_schedule(&alice_account, &authority.pubkey(), &[&authority])
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::NoAuthorityExists));
+}
+
+#[tokio::test]
+async fn transfer_and_burn() {
+    let authority = Keypair::new();
+    let mut context = TestContext::new().await;
+    context
+        .init_token_with_mint(vec![ExtensionInitializationParams::VestingConfig {
+            authority: Some(authority.pubkey()),
+        }])
+        .await
+        .unwrap();
+    let token_context = context.token_context.take().unwrap();
+    let (alice_account, bob_account) = setup_accounts(&token_context, 1_000).await;
+    let TokenContext {
+        token,
+        alice,
+        mint_authority,
+        ..
+    } = &token_context;
+
+    set_unix_timestamp(&context, 0).await;
+    token
+        .create_vesting_schedule(
+            &alice_account,
+            &authority.pubkey(),
+            1_000,
+            1_000,
+            1_500,
+            2_000,
+            &[&authority],
+        )
+        .await
+        .unwrap();
+
+    // fail, nothing vested before the cliff
+    let err = token
+        .transfer(&alice_account, &bob_account, &alice.pubkey(), 1, &[alice])
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::InsufficientVestedFunds));
+    let err = token
+        .burn(&alice_account, &alice.pubkey(), 1, &[alice])
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::InsufficientVestedFunds));
+
+    // the cliff releases half
+    set_unix_timestamp(&context, 1_500).await;
+    token
+        .transfer(&alice_account, &bob_account, &alice.pubkey(), 500, &[alice])
+        .await
+        .unwrap();
+    let err = token
+        .transfer(&alice_account, &bob_account, &alice.pubkey(), 2, &[alice])
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::InsufficientVestedFunds));
+
+    // tokens outside of the schedule are always available
+    token
+        .mint_to(
+            &alice_account,
+            &mint_authority.pubkey(),
+            100,
+            &[mint_authority],
+        )
+        .await
+        .unwrap();
+    token
+        .transfer(&alice_account, &bob_account, &alice.pubkey(), 100, &[alice])
+        .await
+        .unwrap();
+
+    // linear vesting after the cliff
+    set_unix_timestamp(&context, 1_750).await;
+    token
+        .burn(&alice_account, &alice.pubkey(), 250, &[alice])
+        .await
+        .unwrap();
+    let err = token
+        .burn(&alice_account, &alice.pubkey(), 2, &[alice])
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::InsufficientVestedFunds));
+
+    // revoking unlocks everything
+    token
+        .revoke_vesting_schedule(&alice_account, &authority.pubkey(), &[&authority])
+        .await
+        .unwrap();
+    token
+        .transfer(&alice_account, &bob_account, &alice.pubkey(), 250, &[alice])
+        .await
+        .unwrap();
+    let state = token.get_account_info(&alice_account).await.unwrap();
+    assert_eq!(state.base.amount, 0);
+}
+
+#[tokio::test]
+async fn set_authority() {
+    let authority = Keypair::new();
+    let mut context = TestContext::new().await;
+    context
+        .init_token_with_mint(vec![ExtensionInitializationParams::VestingConfig {
+            authority: Some(authority.pubkey()),
+        }])
+        .await
+        .unwrap();
+    let TokenContext { token, .. } = context.token_context.unwrap();
+    let new_authority = Keypair::new();
+
+    // fail, wrong signature
+    let wrong = Keypair::new();
+    let err = token
+        .set_authority(
+            token.get_address(),
+            &wrong.pubkey(),
+            Some(&new_authority.pubkey()),
+            instruction::AuthorityType::Vesting,
+            &[&wrong],
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::OwnerMismatch));
+
+    // success
+    token
+        .set_authority(
+            token.get_address(),
+            &authority.pubkey(),
+            Some(&new_authority.pubkey()),
+            instruction::AuthorityType::Vesting,
+            &[&authority],
+        )
+        .await
+        .unwrap();
+    let state = token.get_mint_info().await.unwrap();
+    let extension = state.get_extension::<VestingConfig>().unwrap();
+    assert_eq!(
+        Option::<Pubkey>::from(extension.authority),
+        Some(new_authority.pubkey()),
+    );
+
+    // set to none
+    token
+        .set_authority(
+            token.get_address(),
+            &new_authority.pubkey(),
+            None,
+            instruction::AuthorityType::Vesting,
+            &[&new_authority],
+        )
+        .await
+        .unwrap();
+    let state = token.get_mint_info().await.unwrap();
+    let extension = state.get_extension::<VestingConfig>().unwrap();
+    assert_eq!(Option::<Pubkey>::from(extension.authority), None);
+
+    // fail set again
+    let err = token
+        .set_authority(
+            token.get_address(),
+            &new_authority.pubkey(),
+            Some(&authority.pubkey()),
+            instruction::AuthorityType::Vesting,
+            &[&new_authority],
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(err, custom_error(0, TokenError::AuthorityTypeNotSupported));
+}
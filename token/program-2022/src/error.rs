@@ -243,6 +243,80 @@ pub enum TokenError {
     /// Ciphertext arithmetic failed
     #[error("Ciphertext arithmetic failed")]
     CiphertextArithmeticFailed,
+    /// Mint supply would exceed the configured maximum supply
+    #[error("Mint supply would exceed the configured maximum supply")]
+    MaxSupplyExceeded,
+    /// Insufficient vested funds
+    #[error("Insufficient vested funds")]
+    InsufficientVestedFunds,
+    /// Vesting schedule timestamps are not ordered
+    #[error("Vesting schedule timestamps are not ordered")]
+    InvalidVestingSchedule,
+    /// Account is not on the mint's transfer allowlist
+    #[error("Account is not on the mint's transfer allowlist")]
+    AccountNotAllowlisted,
+
+    // 65
+    /// Account is on the mint's transfer denylist
+    #[error("Account is on the mint's transfer denylist")]
+    AccountDenylisted,
+    /// Transfer amount exceeds the mint's per-transfer limit
+    #[error("Transfer amount exceeds the mint's per-transfer limit")]
+    TransferLimitExceeded,
+    /// Transfer would exceed the mint's daily limit for the account
+    #[error("Transfer would exceed the mint's daily limit for the account")]
+    DailyTransferLimitExceeded,
+    /// Balance is not available for the requested snapshot
+    #[error("Balance is not available for the requested snapshot")]
+    SnapshotNotAvailable,
+    /// Account has already claimed the latest distribution
+    #[error("Account has already claimed the latest distribution")]
+    DistributionAlreadyClaimed,
+
+    // 70
+    /// Mint has no funded distribution
+    #[error("Mint has no funded distribution")]
+    NoActiveDistribution,
+    /// Account no longer retains a checkpoint for the requested snapshot
+    #[error("Account no longer retains a checkpoint for the requested snapshot")]
+    SnapshotCheckpointEvicted,
+    /// Delegate approval has expired
+    #[error("Delegate approval has expired")]
+    DelegateApprovalExpired,
+    /// Transfer amount exceeds the secondary authority's limit
+    #[error("Transfer amount exceeds the secondary authority's limit")]
+    SecondaryAuthorityLimitExceeded,
+    /// Royalty recipients are invalid
+    #[error("Royalty recipients are invalid")]
+    InvalidRoyaltyRecipients,
+
+    // 75
+    /// Account is not the royalty recipient's destination
+    #[error("Account is not the royalty recipient's destination")]
+    RoyaltyRecipientMismatch,
+    /// Combined royalty and transfer fee rate exceeds 100%
+    #[error("Combined royalty and transfer fee rate exceeds 100%")]
+    RoyaltyAndTransferFeeExceedMaximum,
+    /// Timed freeze has not expired
+    #[error("Timed freeze has not expired")]
+    TimedFreezeNotExpired,
+    /// Destination is not owned by the account's recovery address
+    #[error("Destination is not owned by the account's recovery address")]
+    RecoveryAddressMismatch,
+    /// Account has a pending transfer
+    #[error("Account has a pending transfer")]
+    PendingTransferExists,
+
+    // 80
+    /// No pending transfer to the destination
+    #[error("No pending transfer to the destination")]
+    NoPendingTransfer,
+    /// Escrowed transfers are not supported by this mint
+    #[error("Escrowed transfers are not supported by this mint")]
+    EscrowedTransferNotSupported,
+    /// Mint is paused
+    #[error("Mint is paused")]
+    MintPaused,
 }
 impl From<TokenError> for ProgramError {
     fn from(e: TokenError) -> Self {
@@ -418,6 +492,72 @@ impl PrintProgramError for TokenError {
             TokenError::CiphertextArithmeticFailed => {
                 msg!("Ciphertext arithmetic failed")
             }
+            TokenError::MaxSupplyExceeded => {
+                msg!("Mint supply would exceed the configured maximum supply")
+            }
+            TokenError::InsufficientVestedFunds => {
+                msg!("Insufficient vested funds")
+            }
+            TokenError::InvalidVestingSchedule => {
+                msg!("Vesting schedule timestamps are not ordered")
+            }
+            TokenError::AccountNotAllowlisted => {
+                msg!("Account is not on the mint's transfer allowlist")
+            }
+            TokenError::AccountDenylisted => {
+                msg!("Account is on the mint's transfer denylist")
+            }
+            TokenError::TransferLimitExceeded => {
+                msg!("Transfer amount exceeds the mint's per-transfer limit")
+            }
+            TokenError::DailyTransferLimitExceeded => {
+                msg!("Transfer would exceed the mint's daily limit for the account")
+            }
+            TokenError::SnapshotNotAvailable => {
+                msg!("Balance is not available for the requested snapshot")
+            }
+            TokenError::DistributionAlreadyClaimed => {
+                msg!("Account has already claimed the latest distribution")
+            }
+            TokenError::NoActiveDistribution => {
+                msg!("Mint has no funded distribution")
+            }
+            TokenError::SnapshotCheckpointEvicted => {
+                msg!("Account no longer retains a checkpoint for the requested snapshot")
+            }
+            TokenError::DelegateApprovalExpired => {
+                msg!("Delegate approval has expired")
+            }
+            TokenError::SecondaryAuthorityLimitExceeded => {
+                msg!("Transfer amount exceeds the secondary authority's limit")
+            }
+            TokenError::InvalidRoyaltyRecipients => {
+                msg!("Royalty recipients are invalid")
+            }
+            TokenError::RoyaltyRecipientMismatch => {
+                msg!("Account is not the royalty recipient's destination")
+            }
+            TokenError::RoyaltyAndTransferFeeExceedMaximum => {
+                msg!("Combined royalty and transfer fee rate exceeds 100%")
+            }
+            TokenError::TimedFreezeNotExpired => {
+                msg!("Timed freeze has not expired")
+            }
+            TokenError::RecoveryAddressMismatch => {
+                msg!("Destination is not owned by the account's recovery address")
+            }
+            TokenError::PendingTransferExists => {
+                msg!("Account has a pending transfer")
+            }
+            TokenError::NoPendingTransfer => {
+                msg!("No pending transfer to the destination")
+            }
+            TokenError::EscrowedTransferNotSupported => {
+                msg!("Escrowed transfers are not supported by this mint")
+            }
+            TokenError::MintPaused => {
+                msg!("Mint is paused")
+            }
         }
     }
 }
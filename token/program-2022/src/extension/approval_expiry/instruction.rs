@@ -0,0 +1,99 @@
+#[cfg(feature = "serde-traits")]
+use serde::{Deserialize, Serialize};
+use {
+    crate::{
+        check_program_account,
+        instruction::{encode_instruction, TokenInstruction},
+    },
+    bytemuck::{Pod, Zeroable},
+    num_enum::{IntoPrimitive, TryFromPrimitive},
+    solana_program::{
+        clock::Slot,
+        instruction::{AccountMeta, Instruction},
+        program_error::ProgramError,
+        pubkey::Pubkey,
+    },
+    spl_pod::primitives::PodU64,
+};
+
+/// Approval expiry extension instructions
+#[cfg_attr(feature = "serde-traits", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde-traits", serde(rename_all = "camelCase"))]
+#[derive(Clone, Copy, Debug, PartialEq, IntoPrimitive, TryFromPrimitive)]
+#[repr(u8)]
+pub enum ApprovalExpiryInstruction {
+    /// Approves a delegate, like `ApproveChecked`, until the given slot.
+    /// After that slot, transfers and burns signed by the delegate fail.
+    ///
+    /// Initializes the `ApprovalExpiry` extension on the source account if
+    /// needed, so the account may need to be reallocated first.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner
+    ///   0. `[writable]` The source account.
+    ///   1. `[]` The token mint.
+    ///   2. `[]` The delegate.
+    ///   3. `[signer]` The source account owner.
+    ///
+    ///   * Multisignature owner
+    ///   0. `[writable]` The source account.
+    ///   1. `[]` The token mint.
+    ///   2. `[]` The delegate.
+    ///   3. `[]` The source account's multisignature owner.
+    ///   4. ..4+M `[signer]` M signer accounts
+    ///
+    /// Data expected by this instruction:
+    ///   `crate::extension::approval_expiry::instruction::ApproveInstructionData`
+    Approve,
+}
+
+/// Data expected by `ApprovalExpiryInstruction::Approve`
+#[cfg_attr(feature = "serde-traits", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde-traits", serde(rename_all = "camelCase"))]
+#[derive(Clone, Copy, Pod, Zeroable)]
+#[repr(C)]
+pub struct ApproveInstructionData {
+    /// The amount of tokens the delegate is approved for
+    pub amount: PodU64,
+    /// Expected number of base 10 digits to the right of the decimal place
+    pub decimals: u8,
+    /// The last slot in which the delegate may act
+    pub expiry_slot: PodU64,
+}
+
+/// Create an `Approve` instruction
+#[allow(clippy::too_many_arguments)]
+pub fn approve(
+    token_program_id: &Pubkey,
+    source: &Pubkey,
+    mint: &Pubkey,
+    delegate: &Pubkey,
+    owner: &Pubkey,
+    signers: &[&Pubkey],
+    amount: u64,
+    decimals: u8,
+    expiry_slot: Slot,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    let mut accounts = vec![
+        AccountMeta::new(*source, false),
+        AccountMeta::new_readonly(*mint, false),
+        AccountMeta::new_readonly(*delegate, false),
+        AccountMeta::new_readonly(*owner, signers.is_empty()),
+    ];
+    for signer_pubkey in signers.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+    Ok(encode_instruction(
+        token_program_id,
+        accounts,
+        TokenInstruction::ApprovalExpiryExtension,
+        ApprovalExpiryInstruction::Approve,
+        &ApproveInstructionData {
+            amount: amount.into(),
+            decimals,
+            expiry_slot: expiry_slot.into(),
+        },
+    ))
+}
@@ -0,0 +1,44 @@
+#[cfg(feature = "serde-traits")]
+use serde::{Deserialize, Serialize};
+use {
+    crate::{
+        error::TokenError,
+        extension::{Extension, ExtensionType},
+    },
+    bytemuck::{Pod, Zeroable},
+    solana_program::{clock::Slot, entrypoint::ProgramResult},
+    spl_pod::primitives::PodU64,
+};
+
+/// Instructions for the ApprovalExpiry extension
+pub mod instruction;
+/// Instruction processor for the ApprovalExpiry extension
+pub mod processor;
+
+/// Approval expiry extension data for accounts.
+///
+/// Any `Approve` or `ApproveChecked` clears the expiry, so it only ever
+/// applies to the delegation made alongside it.
+#[repr(C)]
+#[cfg_attr(feature = "serde-traits", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde-traits", serde(rename_all = "camelCase"))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+pub struct ApprovalExpiry {
+    /// Last slot in which the delegate may act, or 0 if the approval does not
+    /// expire
+    pub expiry_slot: PodU64,
+}
+impl ApprovalExpiry {
+    /// Check that the delegate may still act in the given slot
+    pub fn check(&self, slot: Slot) -> ProgramResult {
+        let expiry_slot = u64::from(self.expiry_slot);
+        if expiry_slot != 0 && slot > expiry_slot {
+            Err(TokenError::DelegateApprovalExpired.into())
+        } else {
+            Ok(())
+        }
+    }
+}
+impl Extension for ApprovalExpiry {
+    const TYPE: ExtensionType = ExtensionType::ApprovalExpiry;
+}
@@ -0,0 +1,71 @@
+use {
+    crate::{
+        check_program_account,
+        extension::{
+            approval_expiry::{
+                instruction::{ApprovalExpiryInstruction, ApproveInstructionData},
+                ApprovalExpiry,
+            },
+            BaseStateWithExtensionsMut, PodStateWithExtensionsMut,
+        },
+        instruction::{decode_instruction_data, decode_instruction_type},
+        pod::PodAccount,
+        processor::Processor,
+    },
+    solana_program::{
+        account_info::{next_account_info, AccountInfo},
+        clock::Slot,
+        entrypoint::ProgramResult,
+        msg,
+        pubkey::Pubkey,
+    },
+};
+
+fn process_approve(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    amount: u64,
+    decimals: u8,
+    expiry_slot: Slot,
+) -> ProgramResult {
+    Processor::process_approve(program_id, accounts, amount, Some(decimals))?;
+
+    let account_info_iter = &mut accounts.iter();
+    let source_account_info = next_account_info(account_info_iter)?;
+    let mut source_account_data = source_account_info.data.borrow_mut();
+    let mut source_account =
+        PodStateWithExtensionsMut::<PodAccount>::unpack(&mut source_account_data)?;
+
+    let extension = if let Ok(extension) = source_account.get_extension_mut::<ApprovalExpiry>() {
+        extension
+    } else {
+        source_account.init_extension::<ApprovalExpiry>(true)?
+    };
+    extension.expiry_slot = expiry_slot.into();
+    Ok(())
+}
+
+pub(crate) fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    input: &[u8],
+) -> ProgramResult {
+    check_program_account(program_id)?;
+    match decode_instruction_type(input)? {
+        ApprovalExpiryInstruction::Approve => {
+            msg!("ApprovalExpiryInstruction::Approve");
+            let ApproveInstructionData {
+                amount,
+                decimals,
+                expiry_slot,
+            } = decode_instruction_data(input)?;
+            process_approve(
+                program_id,
+                accounts,
+                u64::from(*amount),
+                *decimals,
+                u64::from(*expiry_slot),
+            )
+        }
+    }
+}
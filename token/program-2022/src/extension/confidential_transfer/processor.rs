@@ -3,7 +3,12 @@
 use crate::extension::transfer_hook;
 #[cfg(feature = "zk-ops")]
 use {
-    crate::extension::non_transferable::NonTransferable,
+    crate::extension::{
+        non_transferable::NonTransferable,
+        pausable::check_not_paused,
+        transfer_restriction::{check_transfer_restriction, TransferRestrictionConfig},
+        vesting::VestingAccount,
+    },
     solana_zk_token_sdk::zk_token_elgamal::ops as syscall,
 };
 use {
@@ -272,6 +277,7 @@ fn process_deposit(
     if mint.get_extension::<NonTransferable>().is_ok() {
         return Err(TokenError::NonTransferable.into());
     }
+    check_not_paused(&mint)?;
 
     check_program_account(token_account_info.owner)?;
     let token_account_data = &mut token_account_info.data.borrow_mut();
@@ -296,6 +302,15 @@ fn process_deposit(
     // Wrapped SOL deposits are not supported because lamports cannot be vanished.
     assert!(!token_account.base.is_native());
 
+    // Locked tokens must not leave the public balance
+    if let Ok(vesting_account) = token_account.get_extension::<VestingAccount>() {
+        vesting_account.check_available(
+            token_account.base.amount.into(),
+            amount,
+            Clock::get()?.unix_timestamp,
+        )?;
+    }
+
     token_account.base.amount = u64::from(token_account.base.amount)
         .checked_sub(amount)
         .ok_or(TokenError::Overflow)?
@@ -370,6 +385,7 @@ fn process_withdraw(
     if mint.get_extension::<NonTransferable>().is_ok() {
         return Err(TokenError::NonTransferable.into());
     }
+    check_not_paused(&mint)?;
 
     check_program_account(token_account_info.owner)?;
     let token_account_data = &mut token_account_info.data.borrow_mut();
@@ -453,6 +469,15 @@ fn process_transfer(
     if mint.get_extension::<NonTransferable>().is_ok() {
         return Err(TokenError::NonTransferable.into());
     }
+    check_not_paused(&mint)?;
+    if let Ok(transfer_restriction) = mint.get_extension::<TransferRestrictionConfig>() {
+        for account_info in [source_account_info, destination_account_info] {
+            check_program_account(account_info.owner)?;
+            let account_data = account_info.data.borrow();
+            let account = PodStateWithExtensions::<PodAccount>::unpack(&account_data)?;
+            check_transfer_restriction(transfer_restriction, &account)?;
+        }
+    }
     let confidential_transfer_mint = mint.get_extension::<ConfidentialTransferMint>()?;
 
     // A `Transfer` instruction must be accompanied by a zero-knowledge proof
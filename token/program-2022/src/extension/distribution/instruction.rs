@@ -0,0 +1,209 @@
+#[cfg(feature = "serde-traits")]
+use serde::{Deserialize, Serialize};
+use {
+    crate::{
+        check_program_account,
+        instruction::{encode_instruction, TokenInstruction},
+    },
+    bytemuck::{Pod, Zeroable},
+    num_enum::{IntoPrimitive, TryFromPrimitive},
+    solana_program::{
+        instruction::{AccountMeta, Instruction},
+        program_error::ProgramError,
+        pubkey::Pubkey,
+    },
+    spl_pod::{optional_keys::OptionalNonZeroPubkey, primitives::PodU64},
+    std::convert::TryInto,
+};
+
+/// Distribution extension instructions
+#[cfg_attr(feature = "serde-traits", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde-traits", serde(rename_all = "camelCase"))]
+#[derive(Clone, Copy, Debug, PartialEq, IntoPrimitive, TryFromPrimitive)]
+#[repr(u8)]
+pub enum DistributionInstruction {
+    /// Initialize a new mint with reward distributions.
+    ///
+    /// Fails if the mint has already been initialized, so must be called before
+    /// `InitializeMint`. The mint must also include the `SnapshotConfig`
+    /// extension.
+    ///
+    /// The mint must have exactly enough space allocated for the base mint (82
+    /// bytes), plus 83 bytes of padding, 1 byte reserved for the account type,
+    /// then space required for this extension, plus any others.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` The mint to initialize.
+    ///
+    /// Data expected by this instruction:
+    ///   `crate::extension::distribution::instruction::InitializeInstructionData`
+    Initialize,
+    /// Move tokens from an account owned by the distribution authority into
+    /// the mint's pool, take a snapshot, and open a new distribution against
+    /// it. Unclaimed rewards from the previous distribution are included.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single authority
+    ///   0. `[writable]` The source account, owned by the authority.
+    ///   1. `[writable]` The token mint.
+    ///   2. `[signer]` The mint's distribution authority.
+    ///
+    ///   * Multisignature authority
+    ///   0. `[writable]` The source account, owned by the authority.
+    ///   1. `[writable]` The token mint.
+    ///   2. `[]` The mint's multisignature distribution authority.
+    ///   3. ..3+M `[signer]` M signer accounts.
+    ///
+    /// Data expected by this instruction:
+    ///   `crate::extension::distribution::instruction::FundDistributionInstructionData`
+    FundDistribution,
+    /// Credit an account with its share of the latest distribution. Each
+    /// account may claim each distribution once.
+    ///
+    /// Accounts only retain `MAX_CHECKPOINTS` snapshot checkpoints, so if the
+    /// account's balance changed across too many snapshots since the
+    /// distribution was funded, the claim fails with
+    /// `SnapshotCheckpointEvicted`.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` The token account.
+    ///   1. `[writable]` The token mint.
+    Claim,
+    /// Move the unclaimed rewards of the latest distribution from the mint's
+    /// pool into the given account, and close the distribution. Accounts that
+    /// have not claimed yet receive nothing from it.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single authority
+    ///   0. `[writable]` The destination account.
+    ///   1. `[writable]` The token mint.
+    ///   2. `[signer]` The mint's distribution authority.
+    ///
+    ///   * Multisignature authority
+    ///   0. `[writable]` The destination account.
+    ///   1. `[writable]` The token mint.
+    ///   2. `[]` The mint's multisignature distribution authority.
+    ///   3. ..3+M `[signer]` M signer accounts.
+    ///
+    /// Data expected by this instruction:
+    ///   None
+    Sweep,
+}
+
+/// Data expected by `DistributionInstruction::Initialize`
+#[cfg_attr(feature = "serde-traits", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde-traits", serde(rename_all = "camelCase"))]
+#[derive(Clone, Copy, Pod, Zeroable)]
+#[repr(C)]
+pub struct InitializeInstructionData {
+    /// The public key for the account that can fund distributions
+    pub authority: OptionalNonZeroPubkey,
+}
+
+/// Data expected by `DistributionInstruction::FundDistribution`
+#[cfg_attr(feature = "serde-traits", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde-traits", serde(rename_all = "camelCase"))]
+#[derive(Clone, Copy, Pod, Zeroable)]
+#[repr(C)]
+pub struct FundDistributionInstructionData {
+    /// The amount of tokens to add to the pool
+    pub amount: PodU64,
+}
+
+/// Create an `Initialize` instruction
+pub fn initialize(
+    token_program_id: &Pubkey,
+    mint: &Pubkey,
+    authority: Option<Pubkey>,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    let accounts = vec![AccountMeta::new(*mint, false)];
+    Ok(encode_instruction(
+        token_program_id,
+        accounts,
+        TokenInstruction::DistributionExtension,
+        DistributionInstruction::Initialize,
+        &InitializeInstructionData {
+            authority: authority.try_into()?,
+        },
+    ))
+}
+
+/// Create a `FundDistribution` instruction
+pub fn fund_distribution(
+    token_program_id: &Pubkey,
+    source: &Pubkey,
+    mint: &Pubkey,
+    authority: &Pubkey,
+    signers: &[&Pubkey],
+    amount: u64,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    let mut accounts = vec![
+        AccountMeta::new(*source, false),
+        AccountMeta::new(*mint, false),
+        AccountMeta::new_readonly(*authority, signers.is_empty()),
+    ];
+    for signer_pubkey in signers.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+    Ok(encode_instruction(
+        token_program_id,
+        accounts,
+        TokenInstruction::DistributionExtension,
+        DistributionInstruction::FundDistribution,
+        &FundDistributionInstructionData {
+            amount: amount.into(),
+        },
+    ))
+}
+
+/// Create a `Claim` instruction
+pub fn claim(
+    token_program_id: &Pubkey,
+    account: &Pubkey,
+    mint: &Pubkey,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    let accounts = vec![
+        AccountMeta::new(*account, false),
+        AccountMeta::new(*mint, false),
+    ];
+    Ok(encode_instruction(
+        token_program_id,
+        accounts,
+        TokenInstruction::DistributionExtension,
+        DistributionInstruction::Claim,
+        &(),
+    ))
+}
+
+/// Create a `Sweep` instruction
+pub fn sweep(
+    token_program_id: &Pubkey,
+    destination: &Pubkey,
+    mint: &Pubkey,
+    authority: &Pubkey,
+    signers: &[&Pubkey],
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    let mut accounts = vec![
+        AccountMeta::new(*destination, false),
+        AccountMeta::new(*mint, false),
+        AccountMeta::new_readonly(*authority, signers.is_empty()),
+    ];
+    for signer_pubkey in signers.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+    Ok(encode_instruction(
+        token_program_id,
+        accounts,
+        TokenInstruction::DistributionExtension,
+        DistributionInstruction::Sweep,
+        &(),
+    ))
+}
@@ -0,0 +1,160 @@
+#[cfg(feature = "serde-traits")]
+use serde::{Deserialize, Serialize};
+use {
+    crate::{
+        error::TokenError,
+        extension::{Extension, ExtensionType},
+    },
+    bytemuck::{Pod, Zeroable},
+    solana_program::entrypoint::ProgramResult,
+    spl_pod::{optional_keys::OptionalNonZeroPubkey, primitives::PodU64},
+};
+
+/// Instructions for the Distribution extension
+pub mod instruction;
+/// Instruction processor for the Distribution extension
+pub mod processor;
+
+/// Distribution extension data for mints.
+///
+/// Rewards are paid in the mint's own tokens. Funding moves tokens from the
+/// authority's account into a pool held on the mint, like withheld transfer
+/// fees, and takes a snapshot. Holders then claim from the pool in proportion
+/// to their balance at that snapshot. Funding a new distribution closes the
+/// previous one, and its unclaimed rewards roll into the new pool.
+///
+/// The eligible supply is the mint's supply minus the pool, so tokens that are
+/// not held as account balances at the snapshot, such as withheld transfer
+/// fees or unharvested royalties, still count towards it. Their share of the
+/// pool can never be claimed, and stays there until the authority sweeps it.
+#[repr(C)]
+#[cfg_attr(feature = "serde-traits", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde-traits", serde(rename_all = "camelCase"))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+pub struct DistributionConfig {
+    /// Authority that can fund distributions
+    pub authority: OptionalNonZeroPubkey,
+    /// Id of the latest distribution, or 0 if none has been funded
+    pub distribution_id: PodU64,
+    /// Snapshot whose balances the latest distribution is paid against
+    pub snapshot_id: PodU64,
+    /// Amount of tokens paid out by the latest distribution
+    pub total_amount: PodU64,
+    /// Supply held by accounts at the snapshot, excluding the pool
+    pub eligible_supply: PodU64,
+    /// Amount of tokens not yet claimed
+    pub pool_amount: PodU64,
+}
+impl DistributionConfig {
+    /// Open a new distribution of the pool plus the given amount, paid against
+    /// the given snapshot, given the mint's supply
+    pub fn fund(&mut self, amount: u64, snapshot_id: u64, supply: u64) -> ProgramResult {
+        let pool_amount = u64::from(self.pool_amount)
+            .checked_add(amount)
+            .ok_or(TokenError::Overflow)?;
+        let eligible_supply = supply
+            .checked_sub(pool_amount)
+            .ok_or(TokenError::Overflow)?;
+        let distribution_id = u64::from(self.distribution_id)
+            .checked_add(1)
+            .ok_or(TokenError::Overflow)?;
+        self.distribution_id = distribution_id.into();
+        self.snapshot_id = snapshot_id.into();
+        self.total_amount = pool_amount.into();
+        self.eligible_supply = eligible_supply.into();
+        self.pool_amount = pool_amount.into();
+        Ok(())
+    }
+
+    /// Calculate the rewards owed to an account holding the given balance at
+    /// the distribution's snapshot
+    pub fn calculate_claim(&self, balance: u64) -> Option<u64> {
+        let eligible_supply = u64::from(self.eligible_supply);
+        if eligible_supply == 0 {
+            return Some(0);
+        }
+        let claim = u128::from(u64::from(self.total_amount))
+            .checked_mul(u128::from(balance))?
+            .checked_div(u128::from(eligible_supply))?;
+        u64::try_from(claim).ok()
+    }
+
+    /// Close the latest distribution and empty the pool, returning the amount
+    /// that was left in it
+    pub fn sweep(&mut self) -> u64 {
+        let pool_amount = u64::from(self.pool_amount);
+        self.total_amount = 0.into();
+        self.pool_amount = 0.into();
+        pool_amount
+    }
+}
+
+/// Claim record for accounts belonging to a mint with distributions
+#[repr(C)]
+#[cfg_attr(feature = "serde-traits", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde-traits", serde(rename_all = "camelCase"))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+pub struct DistributionAccount {
+    /// Id of the latest distribution claimed by the account
+    pub last_claimed_distribution_id: PodU64,
+}
+
+impl Extension for DistributionConfig {
+    const TYPE: ExtensionType = ExtensionType::DistributionConfig;
+}
+
+impl Extension for DistributionAccount {
+    const TYPE: ExtensionType = ExtensionType::DistributionAccount;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fund_and_claim() {
+        let mut config = DistributionConfig::default();
+        // supply of 1_000 includes the 100 just moved into the pool
+        config.fund(100, 1, 1_000).unwrap();
+        assert_eq!(u64::from(config.distribution_id), 1);
+        assert_eq!(u64::from(config.eligible_supply), 900);
+        assert_eq!(config.calculate_claim(450), Some(50));
+        assert_eq!(config.calculate_claim(900), Some(100));
+        assert_eq!(config.calculate_claim(1), Some(0));
+
+        // unclaimed rewards roll into the next distribution
+        config.pool_amount = 40.into();
+        config.fund(60, 2, 1_000).unwrap();
+        assert_eq!(u64::from(config.distribution_id), 2);
+        assert_eq!(u64::from(config.total_amount), 100);
+        assert_eq!(u64::from(config.eligible_supply), 900);
+    }
+
+    #[test]
+    fn sweep() {
+        let mut config = DistributionConfig::default();
+        config.fund(100, 1, 1_000).unwrap();
+        config.pool_amount = 40.into();
+        assert_eq!(config.sweep(), 40);
+        assert_eq!(u64::from(config.distribution_id), 1);
+        assert_eq!(config.calculate_claim(900), Some(0));
+
+        // nothing rolls into the next distribution
+        config.fund(60, 2, 1_000).unwrap();
+        assert_eq!(u64::from(config.total_amount), 60);
+        assert_eq!(u64::from(config.eligible_supply), 940);
+    }
+
+    #[test]
+    fn fund_more_than_supply() {
+        let mut config = DistributionConfig::default();
+        assert_eq!(config.fund(100, 1, 99), Err(TokenError::Overflow.into()));
+    }
+
+    #[test]
+    fn empty_supply() {
+        let mut config = DistributionConfig::default();
+        config.fund(100, 1, 100).unwrap();
+        assert_eq!(config.calculate_claim(0), Some(0));
+    }
+}
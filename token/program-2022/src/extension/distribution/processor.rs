@@ -0,0 +1,246 @@
+use {
+    crate::{
+        check_program_account,
+        error::TokenError,
+        extension::{
+            distribution::{
+                instruction::{
+                    DistributionInstruction, FundDistributionInstructionData,
+                    InitializeInstructionData,
+                },
+                DistributionAccount, DistributionConfig,
+            },
+            pausable::check_not_paused,
+            snapshot::{write_checkpoint, SnapshotAccount, SnapshotConfig},
+            transfer_restriction::{check_transfer_restriction, TransferRestrictionConfig},
+            BaseStateWithExtensions, BaseStateWithExtensionsMut, PodStateWithExtensionsMut,
+        },
+        instruction::{decode_instruction_data, decode_instruction_type},
+        pod::{PodAccount, PodMint},
+        processor::Processor,
+    },
+    solana_program::{
+        account_info::{next_account_info, AccountInfo},
+        entrypoint::ProgramResult,
+        msg,
+        pubkey::Pubkey,
+    },
+    spl_pod::optional_keys::OptionalNonZeroPubkey,
+};
+
+fn process_initialize(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    authority: &OptionalNonZeroPubkey,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let mint_account_info = next_account_info(account_info_iter)?;
+    let mut mint_data = mint_account_info.data.borrow_mut();
+    let mut mint = PodStateWithExtensionsMut::<PodMint>::unpack_uninitialized(&mut mint_data)?;
+
+    let extension = mint.init_extension::<DistributionConfig>(true)?;
+    extension.authority = *authority;
+    Ok(())
+}
+
+fn process_fund_distribution(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    amount: u64,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let source_account_info = next_account_info(account_info_iter)?;
+    let mint_info = next_account_info(account_info_iter)?;
+    let authority_info = next_account_info(account_info_iter)?;
+    let authority_info_data_len = authority_info.data_len();
+
+    check_program_account(source_account_info.owner)?;
+    let mut source_account_data = source_account_info.data.borrow_mut();
+    let mut source_account =
+        PodStateWithExtensionsMut::<PodAccount>::unpack(&mut source_account_data)?;
+    if source_account.base.mint != *mint_info.key {
+        return Err(TokenError::MintMismatch.into());
+    }
+    if source_account.base.is_frozen() {
+        return Err(TokenError::AccountFrozen.into());
+    }
+
+    check_program_account(mint_info.owner)?;
+    let mut mint_data = mint_info.data.borrow_mut();
+    let mut mint = PodStateWithExtensionsMut::<PodMint>::unpack(&mut mint_data)?;
+    check_not_paused(&mint)?;
+    let extension = mint.get_extension::<DistributionConfig>()?;
+    let authority =
+        Option::<Pubkey>::from(extension.authority).ok_or(TokenError::NoAuthorityExists)?;
+
+    Processor::validate_owner(
+        program_id,
+        &authority,
+        authority_info,
+        authority_info_data_len,
+        account_info_iter.as_slice(),
+    )?;
+    if source_account.base.owner != authority {
+        return Err(TokenError::OwnerMismatch.into());
+    }
+
+    let source_amount = u64::from(source_account.base.amount);
+    if source_amount < amount {
+        return Err(TokenError::InsufficientFunds.into());
+    }
+    let snapshot = mint.get_extension_mut::<SnapshotConfig>()?;
+    write_checkpoint(snapshot, &mut source_account)?;
+    source_account.base.amount = source_amount
+        .checked_sub(amount)
+        .ok_or(TokenError::Overflow)?
+        .into();
+
+    let snapshot_id = u64::from(snapshot.snapshot_id)
+        .checked_add(1)
+        .ok_or(TokenError::Overflow)?;
+    snapshot.snapshot_id = snapshot_id.into();
+
+    let supply = u64::from(mint.base.supply);
+    let extension = mint.get_extension_mut::<DistributionConfig>()?;
+    extension.fund(amount, snapshot_id, supply)?;
+    msg!(
+        "Distribution {} at snapshot {}",
+        u64::from(extension.distribution_id),
+        snapshot_id
+    );
+    Ok(())
+}
+
+fn process_claim(accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let token_account_info = next_account_info(account_info_iter)?;
+    let mint_info = next_account_info(account_info_iter)?;
+
+    check_program_account(token_account_info.owner)?;
+    let mut token_account_data = token_account_info.data.borrow_mut();
+    let mut token_account =
+        PodStateWithExtensionsMut::<PodAccount>::unpack(&mut token_account_data)?;
+    if token_account.base.mint != *mint_info.key {
+        return Err(TokenError::MintMismatch.into());
+    }
+    if token_account.base.is_frozen() {
+        return Err(TokenError::AccountFrozen.into());
+    }
+
+    check_program_account(mint_info.owner)?;
+    let mut mint_data = mint_info.data.borrow_mut();
+    let mut mint = PodStateWithExtensionsMut::<PodMint>::unpack(&mut mint_data)?;
+    check_not_paused(&mint)?;
+    if let Ok(transfer_restriction) = mint.get_extension::<TransferRestrictionConfig>() {
+        check_transfer_restriction(transfer_restriction, &token_account)?;
+    }
+    let snapshot = *mint.get_extension::<SnapshotConfig>()?;
+    let extension = mint.get_extension_mut::<DistributionConfig>()?;
+    let distribution_id = u64::from(extension.distribution_id);
+    if distribution_id == 0 || u64::from(extension.total_amount) == 0 {
+        return Err(TokenError::NoActiveDistribution.into());
+    }
+
+    let claim_record = token_account.get_extension_mut::<DistributionAccount>()?;
+    if u64::from(claim_record.last_claimed_distribution_id) >= distribution_id {
+        return Err(TokenError::DistributionAlreadyClaimed.into());
+    }
+    claim_record.last_claimed_distribution_id = distribution_id.into();
+
+    let balance = token_account
+        .get_extension::<SnapshotAccount>()?
+        .balance_at(
+            extension.snapshot_id.into(),
+            snapshot.snapshot_id.into(),
+            token_account.base.amount.into(),
+        )?;
+    let claim = extension
+        .calculate_claim(balance)
+        .ok_or(TokenError::Overflow)?;
+    extension.pool_amount = u64::from(extension.pool_amount)
+        .checked_sub(claim)
+        .ok_or(TokenError::Overflow)?
+        .into();
+
+    write_checkpoint(&snapshot, &mut token_account)?;
+    token_account.base.amount = u64::from(token_account.base.amount)
+        .checked_add(claim)
+        .ok_or(TokenError::Overflow)?
+        .into();
+    Ok(())
+}
+
+fn process_sweep(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let destination_account_info = next_account_info(account_info_iter)?;
+    let mint_info = next_account_info(account_info_iter)?;
+    let authority_info = next_account_info(account_info_iter)?;
+    let authority_info_data_len = authority_info.data_len();
+
+    check_program_account(destination_account_info.owner)?;
+    let mut destination_account_data = destination_account_info.data.borrow_mut();
+    let mut destination_account =
+        PodStateWithExtensionsMut::<PodAccount>::unpack(&mut destination_account_data)?;
+    if destination_account.base.mint != *mint_info.key {
+        return Err(TokenError::MintMismatch.into());
+    }
+    if destination_account.base.is_frozen() {
+        return Err(TokenError::AccountFrozen.into());
+    }
+
+    check_program_account(mint_info.owner)?;
+    let mut mint_data = mint_info.data.borrow_mut();
+    let mut mint = PodStateWithExtensionsMut::<PodMint>::unpack(&mut mint_data)?;
+    check_not_paused(&mint)?;
+    if let Ok(transfer_restriction) = mint.get_extension::<TransferRestrictionConfig>() {
+        check_transfer_restriction(transfer_restriction, &destination_account)?;
+    }
+    let snapshot = *mint.get_extension::<SnapshotConfig>()?;
+    let extension = mint.get_extension_mut::<DistributionConfig>()?;
+    let authority =
+        Option::<Pubkey>::from(extension.authority).ok_or(TokenError::NoAuthorityExists)?;
+
+    Processor::validate_owner(
+        program_id,
+        &authority,
+        authority_info,
+        authority_info_data_len,
+        account_info_iter.as_slice(),
+    )?;
+
+    let amount = extension.sweep();
+    write_checkpoint(&snapshot, &mut destination_account)?;
+    destination_account.base.amount = u64::from(destination_account.base.amount)
+        .checked_add(amount)
+        .ok_or(TokenError::Overflow)?
+        .into();
+    Ok(())
+}
+
+pub(crate) fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    input: &[u8],
+) -> ProgramResult {
+    check_program_account(program_id)?;
+    match decode_instruction_type(input)? {
+        DistributionInstruction::Initialize => {
+            msg!("DistributionInstruction::Initialize");
+            let InitializeInstructionData { authority } = decode_instruction_data(input)?;
+            process_initialize(program_id, accounts, authority)
+        }
+        DistributionInstruction::FundDistribution => {
+            msg!("DistributionInstruction::FundDistribution");
+            let FundDistributionInstructionData { amount } = decode_instruction_data(input)?;
+            process_fund_distribution(program_id, accounts, u64::from(*amount))
+        }
+        DistributionInstruction::Claim => {
+            msg!("DistributionInstruction::Claim");
+            process_claim(accounts)
+        }
+        DistributionInstruction::Sweep => {
+            msg!("DistributionInstruction::Sweep");
+            process_sweep(program_id, accounts)
+        }
+    }
+}
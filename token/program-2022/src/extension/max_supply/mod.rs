@@ -0,0 +1,42 @@
+#[cfg(feature = "serde-traits")]
+use serde::{Deserialize, Serialize};
+use {
+    crate::{
+        error::TokenError,
+        extension::{Extension, ExtensionType},
+    },
+    bytemuck::{Pod, Zeroable},
+    solana_program::entrypoint::ProgramResult,
+    spl_pod::{optional_keys::OptionalNonZeroPubkey, primitives::PodU64},
+};
+
+/// Max supply extension instructions
+pub mod instruction;
+
+/// Max supply extension processor
+pub mod processor;
+
+/// Max supply extension data for mints
+#[repr(C)]
+#[cfg_attr(feature = "serde-traits", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde-traits", serde(rename_all = "camelCase"))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+pub struct MaxSupplyConfig {
+    /// Authority that can update the maximum supply
+    pub authority: OptionalNonZeroPubkey,
+    /// Hard cap on the mint's supply
+    pub max_supply: PodU64,
+}
+impl MaxSupplyConfig {
+    /// Check that the given supply does not exceed the configured maximum
+    pub fn check_supply(&self, supply: u64) -> ProgramResult {
+        if supply > u64::from(self.max_supply) {
+            Err(TokenError::MaxSupplyExceeded.into())
+        } else {
+            Ok(())
+        }
+    }
+}
+impl Extension for MaxSupplyConfig {
+    const TYPE: ExtensionType = ExtensionType::MaxSupplyConfig;
+}
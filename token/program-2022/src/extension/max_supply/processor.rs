@@ -0,0 +1,95 @@
+use {
+    crate::{
+        check_program_account,
+        error::TokenError,
+        extension::{
+            max_supply::{
+                instruction::{
+                    InitializeInstructionData, MaxSupplyInstruction, UpdateInstructionData,
+                },
+                MaxSupplyConfig,
+            },
+            BaseStateWithExtensionsMut, PodStateWithExtensionsMut,
+        },
+        instruction::{decode_instruction_data, decode_instruction_type},
+        pod::PodMint,
+        processor::Processor,
+    },
+    solana_program::{
+        account_info::{next_account_info, AccountInfo},
+        entrypoint::ProgramResult,
+        msg,
+        pubkey::Pubkey,
+    },
+    spl_pod::{optional_keys::OptionalNonZeroPubkey, primitives::PodU64},
+};
+
+fn process_initialize(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    authority: &OptionalNonZeroPubkey,
+    max_supply: &PodU64,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let mint_account_info = next_account_info(account_info_iter)?;
+    let mut mint_data = mint_account_info.data.borrow_mut();
+    let mut mint = PodStateWithExtensionsMut::<PodMint>::unpack_uninitialized(&mut mint_data)?;
+
+    let extension = mint.init_extension::<MaxSupplyConfig>(true)?;
+    extension.authority = *authority;
+    extension.max_supply = *max_supply;
+    Ok(())
+}
+
+fn process_update(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    new_max_supply: &PodU64,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let mint_account_info = next_account_info(account_info_iter)?;
+    let owner_info = next_account_info(account_info_iter)?;
+    let owner_info_data_len = owner_info.data_len();
+
+    let mut mint_data = mint_account_info.data.borrow_mut();
+    let mut mint = PodStateWithExtensionsMut::<PodMint>::unpack(&mut mint_data)?;
+    let supply = u64::from(mint.base.supply);
+    let extension = mint.get_extension_mut::<MaxSupplyConfig>()?;
+    let authority =
+        Option::<Pubkey>::from(extension.authority).ok_or(TokenError::NoAuthorityExists)?;
+
+    Processor::validate_owner(
+        program_id,
+        &authority,
+        owner_info,
+        owner_info_data_len,
+        account_info_iter.as_slice(),
+    )?;
+
+    extension.max_supply = *new_max_supply;
+    // The current supply must always fit under the cap
+    extension.check_supply(supply)
+}
+
+pub(crate) fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    input: &[u8],
+) -> ProgramResult {
+    check_program_account(program_id)?;
+    match decode_instruction_type(input)? {
+        MaxSupplyInstruction::Initialize => {
+            msg!("MaxSupplyInstruction::Initialize");
+            let InitializeInstructionData {
+                authority,
+                max_supply,
+            } = decode_instruction_data(input)?;
+            process_initialize(program_id, accounts, authority, max_supply)
+        }
+        MaxSupplyInstruction::Update => {
+            msg!("MaxSupplyInstruction::Update");
+            let UpdateInstructionData { max_supply } = decode_instruction_data(input)?;
+            process_update(program_id, accounts, max_supply)
+        }
+    }
+}
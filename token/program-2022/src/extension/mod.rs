@@ -6,23 +6,36 @@ use {
     crate::{
         error::TokenError,
         extension::{
+            approval_expiry::ApprovalExpiry,
             confidential_transfer::{ConfidentialTransferAccount, ConfidentialTransferMint},
             confidential_transfer_fee::{
                 ConfidentialTransferFeeAmount, ConfidentialTransferFeeConfig,
             },
             cpi_guard::CpiGuard,
             default_account_state::DefaultAccountState,
+            distribution::{DistributionAccount, DistributionConfig},
             group_member_pointer::GroupMemberPointer,
             group_pointer::GroupPointer,
             immutable_owner::ImmutableOwner,
             interest_bearing_mint::InterestBearingConfig,
+            max_supply::MaxSupplyConfig,
             memo_transfer::MemoTransfer,
             metadata_pointer::MetadataPointer,
             mint_close_authority::MintCloseAuthority,
             non_transferable::{NonTransferable, NonTransferableAccount},
+            pausable::{PausableAccount, PausableConfig},
+            pending_transfer::PendingTransfer,
             permanent_delegate::PermanentDelegate,
+            royalty::{RoyaltyAccount, RoyaltyConfig},
+            secondary_authority::SecondaryAuthority,
+            snapshot::{SnapshotAccount, SnapshotConfig},
+            soul_bound::{SoulBound, SoulBoundAccount},
+            timed_freeze::TimedFreeze,
             transfer_fee::{TransferFeeAmount, TransferFeeConfig},
             transfer_hook::{TransferHook, TransferHookAccount},
+            transfer_limit::{TransferLimitAccount, TransferLimitConfig},
+            transfer_restriction::{TransferRestriction, TransferRestrictionConfig},
+            vesting::{VestingAccount, VestingConfig},
         },
         pod::{PodAccount, PodMint},
         state::{Account, Mint, Multisig, PackedSizeOf},
@@ -47,6 +60,8 @@ use {
     },
 };
 
+/// Approval Expiry extension
+pub mod approval_expiry;
 /// Confidential Transfer extension
 pub mod confidential_transfer;
 /// Confidential Transfer Fee extension
@@ -55,6 +70,8 @@ pub mod confidential_transfer_fee;
 pub mod cpi_guard;
 /// Default Account State extension
 pub mod default_account_state;
+/// Distribution extension
+pub mod distribution;
 /// Group Member Pointer extension
 pub mod group_member_pointer;
 /// Group Pointer extension
@@ -63,6 +80,8 @@ pub mod group_pointer;
 pub mod immutable_owner;
 /// Interest-Bearing Mint extension
 pub mod interest_bearing_mint;
+/// Max Supply extension
+pub mod max_supply;
 /// Memo Transfer extension
 pub mod memo_transfer;
 /// Metadata Pointer extension
@@ -71,10 +90,24 @@ pub mod metadata_pointer;
 pub mod mint_close_authority;
 /// Non Transferable extension
 pub mod non_transferable;
+/// Pausable extension
+pub mod pausable;
+/// Pending Transfer extension
+pub mod pending_transfer;
 /// Permanent Delegate extension
 pub mod permanent_delegate;
 /// Utility to reallocate token accounts
 pub mod reallocate;
+/// Royalty extension
+pub mod royalty;
+/// Secondary Authority extension
+pub mod secondary_authority;
+/// Snapshot extension
+pub mod snapshot;
+/// Soul-Bound extension
+pub mod soul_bound;
+/// Timed Freeze extension
+pub mod timed_freeze;
 /// Token-group extension
 pub mod token_group;
 /// Token-metadata extension
@@ -83,6 +116,14 @@ pub mod token_metadata;
 pub mod transfer_fee;
 /// Transfer Hook extension
 pub mod transfer_hook;
+/// Transfer Limit extension
+pub mod transfer_limit;
+/// Transfer Restriction extension
+pub mod transfer_restriction;
+/// Consistency checks for the extensions on mints and accounts
+pub mod validation;
+/// Vesting extension
+pub mod vesting;
 
 /// Length in TLV structure
 #[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
@@ -759,6 +800,27 @@ pub trait BaseStateWithExtensionsMut<S: BaseState>: BaseStateWithExtensions<S> {
             ExtensionType::TransferHookAccount => {
                 self.init_extension::<TransferHookAccount>(true).map(|_| ())
             }
+            ExtensionType::TransferRestriction => {
+                self.init_extension::<TransferRestriction>(true).map(|_| ())
+            }
+            ExtensionType::TransferLimitAccount => self
+                .init_extension::<TransferLimitAccount>(true)
+                .map(|_| ()),
+            ExtensionType::SnapshotAccount => {
+                self.init_extension::<SnapshotAccount>(true).map(|_| ())
+            }
+            ExtensionType::DistributionAccount => {
+                self.init_extension::<DistributionAccount>(true).map(|_| ())
+            }
+            ExtensionType::RoyaltyAccount => {
+                self.init_extension::<RoyaltyAccount>(true).map(|_| ())
+            }
+            ExtensionType::SoulBoundAccount => {
+                self.init_extension::<SoulBoundAccount>(true).map(|_| ())
+            }
+            ExtensionType::PausableAccount => {
+                self.init_extension::<PausableAccount>(true).map(|_| ())
+            }
             // ConfidentialTransfers are currently opt-in only, so this is a no-op for extra safety
             // on InitializeAccount
             ExtensionType::ConfidentialTransferAccount => Ok(()),
@@ -1101,6 +1163,49 @@ pub enum ExtensionType {
     GroupMemberPointer,
     /// Mint contains token group member configurations
     TokenGroupMember,
+    /// Mint contains a hard cap on its supply
+    MaxSupplyConfig,
+    /// Mint contains an authority that can lock up tokens in accounts
+    VestingConfig,
+    /// Account contains a vesting schedule locking up some of its tokens
+    VestingAccount,
+    /// Mint restricts transfers to allowlisted accounts, or away from
+    /// denylisted accounts
+    TransferRestrictionConfig,
+    /// Account contains allowlist and denylist flags for its mint
+    TransferRestriction,
+    /// Mint caps the amount moved per transfer and per account per day
+    TransferLimitConfig,
+    /// Account contains its daily transfer usage for its mint
+    TransferLimitAccount,
+    /// Mint records balances as of snapshots, for governance
+    SnapshotConfig,
+    /// Account contains its balance checkpoints for its mint's snapshots
+    SnapshotAccount,
+    /// Mint pays out rewards to holders pro-rata at a snapshot
+    DistributionConfig,
+    /// Account contains the latest distribution it claimed
+    DistributionAccount,
+    /// Account's delegate approval expires at a slot
+    ApprovalExpiry,
+    /// Account has a secondary authority limited to small transfers
+    SecondaryAuthority,
+    /// Mint withholds royalties for several recipients on every transfer
+    RoyaltyConfig,
+    /// Account contains the royalties withheld from transfers into it
+    RoyaltyAccount,
+    /// Account is frozen until a timestamp, after which anyone may thaw it
+    TimedFreeze,
+    /// Mint's tokens can only move by recovering an account
+    SoulBound,
+    /// Account contains the owner its tokens may be recovered to
+    SoulBoundAccount,
+    /// Account has tokens in escrow until the recipient accepts them
+    PendingTransfer,
+    /// Mint can be paused, blocking minting, burning and transferring
+    PausableConfig,
+    /// Account belongs to a pausable mint
+    PausableAccount,
     /// Test variable-length mint extension
     #[cfg(test)]
     VariableLenMintTest = u16::MAX - 2,
@@ -1181,6 +1286,29 @@ impl ExtensionType {
             ExtensionType::TokenGroup => pod_get_packed_len::<TokenGroup>(),
             ExtensionType::GroupMemberPointer => pod_get_packed_len::<GroupMemberPointer>(),
             ExtensionType::TokenGroupMember => pod_get_packed_len::<TokenGroupMember>(),
+            ExtensionType::MaxSupplyConfig => pod_get_packed_len::<MaxSupplyConfig>(),
+            ExtensionType::VestingConfig => pod_get_packed_len::<VestingConfig>(),
+            ExtensionType::VestingAccount => pod_get_packed_len::<VestingAccount>(),
+            ExtensionType::TransferRestrictionConfig => {
+                pod_get_packed_len::<TransferRestrictionConfig>()
+            }
+            ExtensionType::TransferRestriction => pod_get_packed_len::<TransferRestriction>(),
+            ExtensionType::TransferLimitConfig => pod_get_packed_len::<TransferLimitConfig>(),
+            ExtensionType::TransferLimitAccount => pod_get_packed_len::<TransferLimitAccount>(),
+            ExtensionType::SnapshotConfig => pod_get_packed_len::<SnapshotConfig>(),
+            ExtensionType::SnapshotAccount => pod_get_packed_len::<SnapshotAccount>(),
+            ExtensionType::DistributionConfig => pod_get_packed_len::<DistributionConfig>(),
+            ExtensionType::DistributionAccount => pod_get_packed_len::<DistributionAccount>(),
+            ExtensionType::ApprovalExpiry => pod_get_packed_len::<ApprovalExpiry>(),
+            ExtensionType::SecondaryAuthority => pod_get_packed_len::<SecondaryAuthority>(),
+            ExtensionType::RoyaltyConfig => pod_get_packed_len::<RoyaltyConfig>(),
+            ExtensionType::RoyaltyAccount => pod_get_packed_len::<RoyaltyAccount>(),
+            ExtensionType::TimedFreeze => pod_get_packed_len::<TimedFreeze>(),
+            ExtensionType::SoulBound => pod_get_packed_len::<SoulBound>(),
+            ExtensionType::SoulBoundAccount => pod_get_packed_len::<SoulBoundAccount>(),
+            ExtensionType::PendingTransfer => pod_get_packed_len::<PendingTransfer>(),
+            ExtensionType::PausableConfig => pod_get_packed_len::<PausableConfig>(),
+            ExtensionType::PausableAccount => pod_get_packed_len::<PausableAccount>(),
             #[cfg(test)]
             ExtensionType::AccountPaddingTest => pod_get_packed_len::<AccountPaddingTest>(),
             #[cfg(test)]
@@ -1244,7 +1372,16 @@ impl ExtensionType {
             | ExtensionType::GroupPointer
             | ExtensionType::TokenGroup
             | ExtensionType::GroupMemberPointer
-            | ExtensionType::TokenGroupMember => AccountType::Mint,
+            | ExtensionType::TokenGroupMember
+            | ExtensionType::MaxSupplyConfig
+            | ExtensionType::VestingConfig
+            | ExtensionType::TransferRestrictionConfig
+            | ExtensionType::TransferLimitConfig
+            | ExtensionType::SnapshotConfig
+            | ExtensionType::DistributionConfig
+            | ExtensionType::RoyaltyConfig
+            | ExtensionType::SoulBound
+            | ExtensionType::PausableConfig => AccountType::Mint,
             ExtensionType::ImmutableOwner
             | ExtensionType::TransferFeeAmount
             | ExtensionType::ConfidentialTransferAccount
@@ -1252,7 +1389,19 @@ impl ExtensionType {
             | ExtensionType::NonTransferableAccount
             | ExtensionType::TransferHookAccount
             | ExtensionType::CpiGuard
-            | ExtensionType::ConfidentialTransferFeeAmount => AccountType::Account,
+            | ExtensionType::ConfidentialTransferFeeAmount
+            | ExtensionType::VestingAccount
+            | ExtensionType::TransferRestriction
+            | ExtensionType::TransferLimitAccount
+            | ExtensionType::SnapshotAccount
+            | ExtensionType::DistributionAccount
+            | ExtensionType::ApprovalExpiry
+            | ExtensionType::SecondaryAuthority
+            | ExtensionType::RoyaltyAccount
+            | ExtensionType::TimedFreeze
+            | ExtensionType::SoulBoundAccount
+            | ExtensionType::PendingTransfer
+            | ExtensionType::PausableAccount => AccountType::Account,
             #[cfg(test)]
             ExtensionType::VariableLenMintTest => AccountType::Mint,
             #[cfg(test)]
@@ -1278,6 +1427,28 @@ impl ExtensionType {
                 ExtensionType::TransferHook => {
                     account_extension_types.push(ExtensionType::TransferHookAccount);
                 }
+                ExtensionType::TransferRestrictionConfig => {
+                    account_extension_types.push(ExtensionType::TransferRestriction);
+                }
+                ExtensionType::TransferLimitConfig => {
+                    account_extension_types.push(ExtensionType::TransferLimitAccount);
+                }
+                ExtensionType::SnapshotConfig => {
+                    account_extension_types.push(ExtensionType::SnapshotAccount);
+                }
+                ExtensionType::DistributionConfig => {
+                    account_extension_types.push(ExtensionType::DistributionAccount);
+                }
+                ExtensionType::RoyaltyConfig => {
+                    account_extension_types.push(ExtensionType::RoyaltyAccount);
+                }
+                ExtensionType::SoulBound => {
+                    account_extension_types.push(ExtensionType::SoulBoundAccount);
+                    account_extension_types.push(ExtensionType::ImmutableOwner);
+                }
+                ExtensionType::PausableConfig => {
+                    account_extension_types.push(ExtensionType::PausableAccount);
+                }
                 #[cfg(test)]
                 ExtensionType::MintPaddingTest => {
                     account_extension_types.push(ExtensionType::AccountPaddingTest);
@@ -1295,10 +1466,28 @@ impl ExtensionType {
         let mut transfer_fee_config = false;
         let mut confidential_transfer_mint = false;
         let mut confidential_transfer_fee_config = false;
+        let mut transfer_limit_config = false;
+        let mut snapshot_config = false;
+        let mut distribution_config = false;
+        let mut royalty_config = false;
+        let mut soul_bound = false;
+        let mut non_transferable = false;
+        let mut vesting_config = false;
+        let mut transfer_hook = false;
+        let mut transfer_restriction_config = false;
 
         for extension_type in mint_extension_types {
             match extension_type {
                 ExtensionType::TransferFeeConfig => transfer_fee_config = true,
+                ExtensionType::TransferLimitConfig => transfer_limit_config = true,
+                ExtensionType::SnapshotConfig => snapshot_config = true,
+                ExtensionType::DistributionConfig => distribution_config = true,
+                ExtensionType::RoyaltyConfig => royalty_config = true,
+                ExtensionType::SoulBound => soul_bound = true,
+                ExtensionType::NonTransferable => non_transferable = true,
+                ExtensionType::VestingConfig => vesting_config = true,
+                ExtensionType::TransferHook => transfer_hook = true,
+                ExtensionType::TransferRestrictionConfig => transfer_restriction_config = true,
                 ExtensionType::ConfidentialTransferMint => confidential_transfer_mint = true,
                 ExtensionType::ConfidentialTransferFeeConfig => {
                     confidential_transfer_fee_config = true
@@ -1316,6 +1505,45 @@ impl ExtensionType {
             return Err(TokenError::InvalidExtensionCombination);
         }
 
+        // Limits cannot be enforced on encrypted amounts
+        if transfer_limit_config && confidential_transfer_mint {
+            return Err(TokenError::InvalidExtensionCombination);
+        }
+
+        // Checkpoints only track the non-confidential balance
+        if snapshot_config && confidential_transfer_mint {
+            return Err(TokenError::InvalidExtensionCombination);
+        }
+
+        // Distributions are paid against snapshot balances
+        if distribution_config && !snapshot_config {
+            return Err(TokenError::InvalidExtensionCombination);
+        }
+
+        // Royalties cannot be withheld from encrypted amounts
+        if royalty_config && confidential_transfer_mint {
+            return Err(TokenError::InvalidExtensionCombination);
+        }
+
+        // Recovery moves the public balance only, ignoring any lockup, and
+        // non-transferable mints have no recovery at all
+        if soul_bound && (confidential_transfer_mint || vesting_config || non_transferable) {
+            return Err(TokenError::InvalidExtensionCombination);
+        }
+
+        // Recovery is the only way soul-bound tokens move, and it is not a
+        // transfer, so transfer fees, royalties, limits, hooks and
+        // restrictions would never apply
+        if soul_bound
+            && (transfer_fee_config
+                || royalty_config
+                || transfer_limit_config
+                || transfer_hook
+                || transfer_restriction_config)
+        {
+            return Err(TokenError::InvalidExtensionCombination);
+        }
+
         Ok(())
     }
 }
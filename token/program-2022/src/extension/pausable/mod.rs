@@ -0,0 +1,54 @@
+#[cfg(feature = "serde-traits")]
+use serde::{Deserialize, Serialize};
+use {
+    crate::{
+        error::TokenError,
+        extension::{BaseState, BaseStateWithExtensions, Extension, ExtensionType},
+    },
+    bytemuck::{Pod, Zeroable},
+    solana_program::entrypoint::ProgramResult,
+    spl_pod::{optional_keys::OptionalNonZeroPubkey, primitives::PodBool},
+};
+
+/// Instructions for the Pausable extension
+pub mod instruction;
+/// Instruction processor for the Pausable extension
+pub mod processor;
+
+/// Pausable extension data for mints
+#[repr(C)]
+#[cfg_attr(feature = "serde-traits", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde-traits", serde(rename_all = "camelCase"))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+pub struct PausableConfig {
+    /// Authority that can pause or resume activity on the mint
+    pub authority: OptionalNonZeroPubkey,
+    /// Whether minting, burning and transferring are currently paused
+    pub paused: PodBool,
+}
+
+/// Indicates that the tokens from this account belong to a pausable mint
+#[cfg_attr(feature = "serde-traits", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde-traits", serde(rename_all = "camelCase"))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+#[repr(transparent)]
+pub struct PausableAccount;
+
+impl Extension for PausableConfig {
+    const TYPE: ExtensionType = ExtensionType::PausableConfig;
+}
+
+impl Extension for PausableAccount {
+    const TYPE: ExtensionType = ExtensionType::PausableAccount;
+}
+
+/// Checks the TLV data for a paused `PausableConfig`, failing with
+/// `MintPaused` if one is found
+pub fn check_not_paused<S: BaseState, BSE: BaseStateWithExtensions<S>>(
+    state: &BSE,
+) -> ProgramResult {
+    match state.get_extension::<PausableConfig>() {
+        Ok(extension) if bool::from(extension.paused) => Err(TokenError::MintPaused.into()),
+        _ => Ok(()),
+    }
+}
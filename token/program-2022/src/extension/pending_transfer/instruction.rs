@@ -0,0 +1,202 @@
+#[cfg(feature = "serde-traits")]
+use serde::{Deserialize, Serialize};
+use {
+    crate::{
+        check_program_account,
+        instruction::{encode_instruction, TokenInstruction},
+    },
+    bytemuck::{Pod, Zeroable},
+    num_enum::{IntoPrimitive, TryFromPrimitive},
+    solana_program::{
+        instruction::{AccountMeta, Instruction},
+        program_error::ProgramError,
+        pubkey::Pubkey,
+    },
+    spl_pod::primitives::PodU64,
+};
+
+/// Pending transfer extension instructions
+#[cfg_attr(feature = "serde-traits", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde-traits", serde(rename_all = "camelCase"))]
+#[derive(Clone, Copy, Debug, PartialEq, IntoPrimitive, TryFromPrimitive)]
+#[repr(u8)]
+pub enum PendingTransferInstruction {
+    /// Move tokens out of the source account into escrow, to be credited to
+    /// the destination once its owner accepts. Each account may have one
+    /// pending transfer at a time.
+    ///
+    /// Not supported for mints with transfer fees, transfer hooks, royalties
+    /// or transfer limits, since escrowed transfers skip them, nor for mints
+    /// with snapshots, since escrowed tokens are not held by either account.
+    ///
+    /// Initializes the `PendingTransfer` extension on the source account if
+    /// needed, so the account may need to be reallocated first.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner
+    ///   0. `[writable]` The source account.
+    ///   1. `[]` The token mint.
+    ///   2. `[]` The destination account.
+    ///   3. `[signer]` The source account's owner.
+    ///
+    ///   * Multisignature owner
+    ///   0. `[writable]` The source account.
+    ///   1. `[]` The token mint.
+    ///   2. `[]` The destination account.
+    ///   3. `[]` The source account's multisignature owner.
+    ///   4. ..4+M `[signer]` M signer accounts.
+    ///
+    /// Data expected by this instruction:
+    ///   `crate::extension::pending_transfer::instruction::InitiateTransferInstructionData`
+    InitiateTransfer,
+    /// Credit the escrowed amount to the destination account. Fails if either
+    /// account has been restricted since the transfer was initiated.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner
+    ///   0. `[writable]` The source account.
+    ///   1. `[]` The token mint.
+    ///   2. `[writable]` The destination account.
+    ///   3. `[signer]` The destination account's owner.
+    ///
+    ///   * Multisignature owner
+    ///   0. `[writable]` The source account.
+    ///   1. `[]` The token mint.
+    ///   2. `[writable]` The destination account.
+    ///   3. `[]` The destination account's multisignature owner.
+    ///   4. ..4+M `[signer]` M signer accounts.
+    AcceptTransfer,
+    /// Return the escrowed amount to the source account. Signed by the owner
+    /// of either account, so the recipient can also reject the transfer.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner
+    ///   0. `[writable]` The source account.
+    ///   1. `[]` The token mint.
+    ///   2. `[]` The destination account.
+    ///   3. `[signer]` The owner of the source or destination account.
+    ///
+    ///   * Multisignature owner
+    ///   0. `[writable]` The source account.
+    ///   1. `[]` The token mint.
+    ///   2. `[]` The destination account.
+    ///   3. `[]` The multisignature owner of the source or destination account.
+    ///   4. ..4+M `[signer]` M signer accounts.
+    CancelTransfer,
+}
+
+/// Data expected by `PendingTransferInstruction::InitiateTransfer`
+#[cfg_attr(feature = "serde-traits", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde-traits", serde(rename_all = "camelCase"))]
+#[derive(Clone, Copy, Pod, Zeroable)]
+#[repr(C)]
+pub struct InitiateTransferInstructionData {
+    /// The amount of tokens to escrow
+    pub amount: PodU64,
+    /// Expected number of base 10 digits to the right of the decimal place
+    pub decimals: u8,
+}
+
+#[allow(clippy::too_many_arguments)]
+fn encode(
+    token_program_id: &Pubkey,
+    source: &Pubkey,
+    mint: &Pubkey,
+    destination: AccountMeta,
+    authority: &Pubkey,
+    signers: &[&Pubkey],
+    instruction_type: PendingTransferInstruction,
+    instruction_data: &impl Pod,
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new(*source, false),
+        AccountMeta::new_readonly(*mint, false),
+        destination,
+        AccountMeta::new_readonly(*authority, signers.is_empty()),
+    ];
+    for signer_pubkey in signers.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+    encode_instruction(
+        token_program_id,
+        accounts,
+        TokenInstruction::PendingTransferExtension,
+        instruction_type,
+        instruction_data,
+    )
+}
+
+/// Create an `InitiateTransfer` instruction
+#[allow(clippy::too_many_arguments)]
+pub fn initiate_transfer(
+    token_program_id: &Pubkey,
+    source: &Pubkey,
+    mint: &Pubkey,
+    destination: &Pubkey,
+    owner: &Pubkey,
+    signers: &[&Pubkey],
+    amount: u64,
+    decimals: u8,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    Ok(encode(
+        token_program_id,
+        source,
+        mint,
+        AccountMeta::new_readonly(*destination, false),
+        owner,
+        signers,
+        PendingTransferInstruction::InitiateTransfer,
+        &InitiateTransferInstructionData {
+            amount: amount.into(),
+            decimals,
+        },
+    ))
+}
+
+/// Create an `AcceptTransfer` instruction
+pub fn accept_transfer(
+    token_program_id: &Pubkey,
+    source: &Pubkey,
+    mint: &Pubkey,
+    destination: &Pubkey,
+    destination_owner: &Pubkey,
+    signers: &[&Pubkey],
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    Ok(encode(
+        token_program_id,
+        source,
+        mint,
+        AccountMeta::new(*destination, false),
+        destination_owner,
+        signers,
+        PendingTransferInstruction::AcceptTransfer,
+        &(),
+    ))
+}
+
+/// Create a `CancelTransfer` instruction
+pub fn cancel_transfer(
+    token_program_id: &Pubkey,
+    source: &Pubkey,
+    mint: &Pubkey,
+    destination: &Pubkey,
+    authority: &Pubkey,
+    signers: &[&Pubkey],
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    Ok(encode(
+        token_program_id,
+        source,
+        mint,
+        AccountMeta::new_readonly(*destination, false),
+        authority,
+        signers,
+        PendingTransferInstruction::CancelTransfer,
+        &(),
+    ))
+}
@@ -0,0 +1,45 @@
+#[cfg(feature = "serde-traits")]
+use serde::{Deserialize, Serialize};
+use {
+    crate::{
+        error::TokenError,
+        extension::{Extension, ExtensionType},
+    },
+    bytemuck::{Pod, Zeroable},
+    solana_program::{entrypoint::ProgramResult, pubkey::Pubkey},
+    spl_pod::{optional_keys::OptionalNonZeroPubkey, primitives::PodU64},
+};
+
+/// Instructions for the PendingTransfer extension
+pub mod instruction;
+/// Instruction processor for the PendingTransfer extension
+pub mod processor;
+
+/// Pending transfer extension data for accounts.
+///
+/// The escrowed amount is taken out of the account's balance when the
+/// transfer is initiated, so neither party can spend it until the transfer is
+/// accepted or cancelled.
+#[repr(C)]
+#[cfg_attr(feature = "serde-traits", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde-traits", serde(rename_all = "camelCase"))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+pub struct PendingTransfer {
+    /// Token account the transfer is going to, or none if nothing is pending
+    pub destination: OptionalNonZeroPubkey,
+    /// Amount held in escrow
+    pub amount: PodU64,
+}
+impl PendingTransfer {
+    /// Check if the extension is in a closable state
+    pub fn closable(&self) -> ProgramResult {
+        if Option::<Pubkey>::from(self.destination).is_none() {
+            Ok(())
+        } else {
+            Err(TokenError::PendingTransferExists.into())
+        }
+    }
+}
+impl Extension for PendingTransfer {
+    const TYPE: ExtensionType = ExtensionType::PendingTransfer;
+}
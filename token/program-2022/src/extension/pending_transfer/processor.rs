@@ -0,0 +1,290 @@
+use {
+    crate::{
+        check_program_account,
+        error::TokenError,
+        extension::{
+            confidential_transfer::ConfidentialTransferAccount,
+            cpi_guard::{in_cpi, CpiGuard},
+            memo_transfer::{check_previous_sibling_instruction_is_memo, memo_required},
+            non_transferable::NonTransferableAccount,
+            pausable::check_not_paused,
+            pending_transfer::{
+                instruction::{InitiateTransferInstructionData, PendingTransferInstruction},
+                PendingTransfer,
+            },
+            royalty::RoyaltyConfig,
+            snapshot::SnapshotConfig,
+            soul_bound::SoulBoundAccount,
+            transfer_fee::TransferFeeConfig,
+            transfer_hook::TransferHook,
+            transfer_limit::TransferLimitConfig,
+            transfer_restriction::{check_transfer_restriction, TransferRestrictionConfig},
+            vesting::VestingAccount,
+            BaseStateWithExtensions, BaseStateWithExtensionsMut, PodStateWithExtensions,
+            PodStateWithExtensionsMut,
+        },
+        instruction::{decode_instruction_data, decode_instruction_type},
+        pod::{PodAccount, PodMint},
+        processor::Processor,
+    },
+    solana_program::{
+        account_info::{next_account_info, AccountInfo},
+        clock::Clock,
+        entrypoint::ProgramResult,
+        msg,
+        pubkey::Pubkey,
+        sysvar::Sysvar,
+    },
+    std::convert::TryInto,
+};
+
+fn process_initiate_transfer(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    amount: u64,
+    expected_decimals: u8,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let source_account_info = next_account_info(account_info_iter)?;
+    let mint_info = next_account_info(account_info_iter)?;
+    let destination_account_info = next_account_info(account_info_iter)?;
+    let owner_info = next_account_info(account_info_iter)?;
+    let owner_info_data_len = owner_info.data_len();
+
+    if source_account_info.key == destination_account_info.key {
+        return Err(TokenError::InvalidState.into());
+    }
+
+    check_program_account(source_account_info.owner)?;
+    let mut source_account_data = source_account_info.data.borrow_mut();
+    let mut source_account =
+        PodStateWithExtensionsMut::<PodAccount>::unpack(&mut source_account_data)?;
+    if source_account.base.mint != *mint_info.key {
+        return Err(TokenError::MintMismatch.into());
+    }
+    if source_account.base.is_frozen() {
+        return Err(TokenError::AccountFrozen.into());
+    }
+    if source_account.base.is_native() {
+        return Err(TokenError::NonNativeNotSupported.into());
+    }
+    let source_amount = u64::from(source_account.base.amount);
+    if source_amount < amount {
+        return Err(TokenError::InsufficientFunds.into());
+    }
+    if source_account
+        .get_extension::<NonTransferableAccount>()
+        .is_ok()
+        || source_account.get_extension::<SoulBoundAccount>().is_ok()
+    {
+        return Err(TokenError::NonTransferable.into());
+    }
+    if let Ok(extension) = source_account.get_extension::<VestingAccount>() {
+        extension.check_available(source_amount, amount, Clock::get()?.unix_timestamp)?;
+    }
+
+    Processor::validate_owner(
+        program_id,
+        &source_account.base.owner,
+        owner_info,
+        owner_info_data_len,
+        account_info_iter.as_slice(),
+    )?;
+    if let Ok(cpi_guard) = source_account.get_extension::<CpiGuard>() {
+        if cpi_guard.lock_cpi.into() && in_cpi() {
+            return Err(TokenError::CpiGuardTransferBlocked.into());
+        }
+    }
+
+    check_program_account(mint_info.owner)?;
+    let mint_data = mint_info.data.borrow();
+    let mint = PodStateWithExtensions::<PodMint>::unpack(&mint_data)?;
+    if expected_decimals != mint.base.decimals {
+        return Err(TokenError::MintDecimalsMismatch.into());
+    }
+    check_not_paused(&mint)?;
+    if mint.get_extension::<TransferFeeConfig>().is_ok()
+        || mint.get_extension::<TransferHook>().is_ok()
+        || mint.get_extension::<RoyaltyConfig>().is_ok()
+        || mint.get_extension::<TransferLimitConfig>().is_ok()
+        || mint.get_extension::<SnapshotConfig>().is_ok()
+    {
+        return Err(TokenError::EscrowedTransferNotSupported.into());
+    }
+    if let Ok(transfer_restriction) = mint.get_extension::<TransferRestrictionConfig>() {
+        check_transfer_restriction(transfer_restriction, &source_account)?;
+    }
+
+    check_program_account(destination_account_info.owner)?;
+    let destination_account_data = destination_account_info.data.borrow();
+    let destination_account =
+        PodStateWithExtensions::<PodAccount>::unpack(&destination_account_data)?;
+    if destination_account.base.mint != *mint_info.key {
+        return Err(TokenError::MintMismatch.into());
+    }
+
+    if let Ok(extension) = source_account.get_extension::<PendingTransfer>() {
+        extension.closable()?;
+    }
+    source_account.base.amount = source_amount
+        .checked_sub(amount)
+        .ok_or(TokenError::Overflow)?
+        .into();
+
+    let extension = if let Ok(extension) = source_account.get_extension_mut::<PendingTransfer>() {
+        extension
+    } else {
+        source_account.init_extension::<PendingTransfer>(true)?
+    };
+    extension.destination = Some(*destination_account_info.key).try_into()?;
+    extension.amount = amount.into();
+    Ok(())
+}
+
+fn process_accept_transfer(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let source_account_info = next_account_info(account_info_iter)?;
+    let mint_info = next_account_info(account_info_iter)?;
+    let destination_account_info = next_account_info(account_info_iter)?;
+    let owner_info = next_account_info(account_info_iter)?;
+    let owner_info_data_len = owner_info.data_len();
+
+    check_program_account(source_account_info.owner)?;
+    let mut source_account_data = source_account_info.data.borrow_mut();
+    let mut source_account =
+        PodStateWithExtensionsMut::<PodAccount>::unpack(&mut source_account_data)?;
+    if source_account.base.mint != *mint_info.key {
+        return Err(TokenError::MintMismatch.into());
+    }
+    let extension = source_account.get_extension_mut::<PendingTransfer>()?;
+    if Option::<Pubkey>::from(extension.destination) != Some(*destination_account_info.key) {
+        return Err(TokenError::NoPendingTransfer.into());
+    }
+    let amount = u64::from(extension.amount);
+    *extension = PendingTransfer::default();
+
+    check_program_account(destination_account_info.owner)?;
+    let mut destination_account_data = destination_account_info.data.borrow_mut();
+    let destination_account =
+        PodStateWithExtensionsMut::<PodAccount>::unpack(&mut destination_account_data)?;
+    if destination_account.base.mint != *mint_info.key {
+        return Err(TokenError::MintMismatch.into());
+    }
+    if destination_account.base.is_frozen() {
+        return Err(TokenError::AccountFrozen.into());
+    }
+    Processor::validate_owner(
+        program_id,
+        &destination_account.base.owner,
+        owner_info,
+        owner_info_data_len,
+        account_info_iter.as_slice(),
+    )?;
+
+    check_program_account(mint_info.owner)?;
+    let mint_data = mint_info.data.borrow();
+    let mint = PodStateWithExtensions::<PodMint>::unpack(&mint_data)?;
+    check_not_paused(&mint)?;
+    if let Ok(transfer_restriction) = mint.get_extension::<TransferRestrictionConfig>() {
+        check_transfer_restriction(transfer_restriction, &source_account)?;
+        check_transfer_restriction(transfer_restriction, &destination_account)?;
+    }
+    if memo_required(&destination_account) {
+        check_previous_sibling_instruction_is_memo()?;
+    }
+    if let Ok(confidential_transfer_state) =
+        destination_account.get_extension::<ConfidentialTransferAccount>()
+    {
+        confidential_transfer_state.non_confidential_transfer_allowed()?
+    }
+    destination_account.base.amount = u64::from(destination_account.base.amount)
+        .checked_add(amount)
+        .ok_or(TokenError::Overflow)?
+        .into();
+    Ok(())
+}
+
+fn process_cancel_transfer(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let source_account_info = next_account_info(account_info_iter)?;
+    let mint_info = next_account_info(account_info_iter)?;
+    let destination_account_info = next_account_info(account_info_iter)?;
+    let authority_info = next_account_info(account_info_iter)?;
+    let authority_info_data_len = authority_info.data_len();
+
+    check_program_account(source_account_info.owner)?;
+    let mut source_account_data = source_account_info.data.borrow_mut();
+    let mut source_account =
+        PodStateWithExtensionsMut::<PodAccount>::unpack(&mut source_account_data)?;
+    if source_account.base.mint != *mint_info.key {
+        return Err(TokenError::MintMismatch.into());
+    }
+    if source_account.base.is_frozen() {
+        return Err(TokenError::AccountFrozen.into());
+    }
+    let extension = source_account.get_extension::<PendingTransfer>()?;
+    if Option::<Pubkey>::from(extension.destination) != Some(*destination_account_info.key) {
+        return Err(TokenError::NoPendingTransfer.into());
+    }
+    let amount = u64::from(extension.amount);
+
+    // The destination is only read if the source owner did not sign, so the
+    // sender can still cancel after the recipient closes the destination
+    if authority_info.key == &source_account.base.owner {
+        Processor::validate_owner(
+            program_id,
+            &source_account.base.owner,
+            authority_info,
+            authority_info_data_len,
+            account_info_iter.as_slice(),
+        )?;
+    } else {
+        check_program_account(destination_account_info.owner)?;
+        let destination_account_data = destination_account_info.data.borrow();
+        let destination_account =
+            PodStateWithExtensions::<PodAccount>::unpack(&destination_account_data)?;
+        Processor::validate_owner(
+            program_id,
+            &destination_account.base.owner,
+            authority_info,
+            authority_info_data_len,
+            account_info_iter.as_slice(),
+        )?;
+    }
+
+    check_program_account(mint_info.owner)?;
+    let mint_data = mint_info.data.borrow();
+    let mint = PodStateWithExtensions::<PodMint>::unpack(&mint_data)?;
+    check_not_paused(&mint)?;
+
+    *source_account.get_extension_mut::<PendingTransfer>()? = PendingTransfer::default();
+    source_account.base.amount = u64::from(source_account.base.amount)
+        .checked_add(amount)
+        .ok_or(TokenError::Overflow)?
+        .into();
+    Ok(())
+}
+
+pub(crate) fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    input: &[u8],
+) -> ProgramResult {
+    check_program_account(program_id)?;
+    match decode_instruction_type(input)? {
+        PendingTransferInstruction::InitiateTransfer => {
+            msg!("PendingTransferInstruction::InitiateTransfer");
+            let InitiateTransferInstructionData { amount, decimals } =
+                decode_instruction_data(input)?;
+            process_initiate_transfer(program_id, accounts, u64::from(*amount), *decimals)
+        }
+        PendingTransferInstruction::AcceptTransfer => {
+            msg!("PendingTransferInstruction::AcceptTransfer");
+            process_accept_transfer(program_id, accounts)
+        }
+        PendingTransferInstruction::CancelTransfer => {
+            msg!("PendingTransferInstruction::CancelTransfer");
+            process_cancel_transfer(program_id, accounts)
+        }
+    }
+}
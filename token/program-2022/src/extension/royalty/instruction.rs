@@ -0,0 +1,207 @@
+#[cfg(feature = "serde-traits")]
+use serde::{Deserialize, Serialize};
+use {
+    crate::{
+        check_program_account,
+        error::TokenError,
+        extension::royalty::{RoyaltyRecipient, MAX_ROYALTY_RECIPIENTS},
+        instruction::{encode_instruction, TokenInstruction},
+    },
+    bytemuck::{Pod, Zeroable},
+    num_enum::{IntoPrimitive, TryFromPrimitive},
+    solana_program::{
+        instruction::{AccountMeta, Instruction},
+        program_error::ProgramError,
+        pubkey::Pubkey,
+    },
+    spl_pod::optional_keys::OptionalNonZeroPubkey,
+    std::convert::TryInto,
+};
+
+/// Royalty extension instructions
+#[cfg_attr(feature = "serde-traits", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde-traits", serde(rename_all = "camelCase"))]
+#[derive(Clone, Copy, Debug, PartialEq, IntoPrimitive, TryFromPrimitive)]
+#[repr(u8)]
+pub enum RoyaltyInstruction {
+    /// Initialize a new mint with royalties paid to up to
+    /// `MAX_ROYALTY_RECIPIENTS` token accounts. The combined rate may not
+    /// exceed 100%.
+    ///
+    /// Fails if the mint has already been initialized, so must be called before
+    /// `InitializeMint`.
+    ///
+    /// The mint must have exactly enough space allocated for the base mint (82
+    /// bytes), plus 83 bytes of padding, 1 byte reserved for the account type,
+    /// then space required for this extension, plus any others.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` The mint to initialize.
+    ///
+    /// Data expected by this instruction:
+    ///   `crate::extension::royalty::instruction::InitializeInstructionData`
+    Initialize,
+    /// Permissionless instruction to move the royalties withheld for one
+    /// recipient into its destination account.
+    ///
+    /// Succeeds for source accounts that fail to harvest, logging the error.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[]` The token mint.
+    ///   1. `[writable]` The recipient's destination account.
+    ///   2. ..2+N `[writable]` The source accounts to harvest from.
+    ///
+    /// Data expected by this instruction:
+    ///   `crate::extension::royalty::instruction::HarvestInstructionData`
+    Harvest,
+    /// Replace the royalty recipients. The combined rate may not exceed 100%,
+    /// including the mint's transfer fee, if any.
+    ///
+    /// Royalties already withheld for a recipient index are harvested to the
+    /// new recipient at that index, so recipients cannot be removed, only
+    /// replaced or given a zero rate.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single authority
+    ///   0. `[writable]` The mint.
+    ///   1. `[signer]` The royalty authority.
+    ///
+    ///   * Multisignature authority
+    ///   0. `[writable]` The mint.
+    ///   1. `[]` The mint's multisignature royalty authority.
+    ///   2. ..2+M `[signer]` M signer accounts.
+    ///
+    /// Data expected by this instruction:
+    ///   `crate::extension::royalty::instruction::UpdateRecipientsInstructionData`
+    UpdateRecipients,
+}
+
+/// Data expected by `RoyaltyInstruction::Initialize`
+#[cfg_attr(feature = "serde-traits", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde-traits", serde(rename_all = "camelCase"))]
+#[derive(Clone, Copy, Pod, Zeroable)]
+#[repr(C)]
+pub struct InitializeInstructionData {
+    /// The public key for the account that can update the recipients
+    pub authority: OptionalNonZeroPubkey,
+    /// The number of recipients in use
+    pub recipient_count: u8,
+    /// The recipients, padded with defaults
+    pub recipients: [RoyaltyRecipient; MAX_ROYALTY_RECIPIENTS],
+}
+
+/// Data expected by `RoyaltyInstruction::Harvest`
+#[cfg_attr(feature = "serde-traits", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde-traits", serde(rename_all = "camelCase"))]
+#[derive(Clone, Copy, Pod, Zeroable)]
+#[repr(C)]
+pub struct HarvestInstructionData {
+    /// The index of the recipient in the mint's configuration
+    pub recipient_index: u8,
+}
+
+/// Data expected by `RoyaltyInstruction::UpdateRecipients`
+#[cfg_attr(feature = "serde-traits", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde-traits", serde(rename_all = "camelCase"))]
+#[derive(Clone, Copy, Pod, Zeroable)]
+#[repr(C)]
+pub struct UpdateRecipientsInstructionData {
+    /// The number of recipients in use
+    pub recipient_count: u8,
+    /// The recipients, padded with defaults
+    pub recipients: [RoyaltyRecipient; MAX_ROYALTY_RECIPIENTS],
+}
+
+fn pack_recipients(
+    recipients: &[(Pubkey, u16)],
+) -> Result<[RoyaltyRecipient; MAX_ROYALTY_RECIPIENTS], ProgramError> {
+    if recipients.len() > MAX_ROYALTY_RECIPIENTS {
+        return Err(TokenError::InvalidRoyaltyRecipients.into());
+    }
+    let mut packed = [RoyaltyRecipient::default(); MAX_ROYALTY_RECIPIENTS];
+    for (recipient, (destination, basis_points)) in packed.iter_mut().zip(recipients) {
+        recipient.destination = *destination;
+        recipient.basis_points = (*basis_points).into();
+    }
+    Ok(packed)
+}
+
+/// Create an `Initialize` instruction, given destination and basis point
+/// pairs
+pub fn initialize(
+    token_program_id: &Pubkey,
+    mint: &Pubkey,
+    authority: Option<Pubkey>,
+    recipients: &[(Pubkey, u16)],
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    let accounts = vec![AccountMeta::new(*mint, false)];
+    Ok(encode_instruction(
+        token_program_id,
+        accounts,
+        TokenInstruction::RoyaltyExtension,
+        RoyaltyInstruction::Initialize,
+        &InitializeInstructionData {
+            authority: authority.try_into()?,
+            recipient_count: recipients.len() as u8,
+            recipients: pack_recipients(recipients)?,
+        },
+    ))
+}
+
+/// Create a `Harvest` instruction
+pub fn harvest(
+    token_program_id: &Pubkey,
+    mint: &Pubkey,
+    destination: &Pubkey,
+    sources: &[&Pubkey],
+    recipient_index: u8,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*mint, false),
+        AccountMeta::new(*destination, false),
+    ];
+    for source in sources.iter() {
+        accounts.push(AccountMeta::new(**source, false));
+    }
+    Ok(encode_instruction(
+        token_program_id,
+        accounts,
+        TokenInstruction::RoyaltyExtension,
+        RoyaltyInstruction::Harvest,
+        &HarvestInstructionData { recipient_index },
+    ))
+}
+
+/// Create an `UpdateRecipients` instruction, given destination and basis point
+/// pairs
+pub fn update_recipients(
+    token_program_id: &Pubkey,
+    mint: &Pubkey,
+    authority: &Pubkey,
+    signers: &[&Pubkey],
+    recipients: &[(Pubkey, u16)],
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    let mut accounts = vec![
+        AccountMeta::new(*mint, false),
+        AccountMeta::new_readonly(*authority, signers.is_empty()),
+    ];
+    for signer_pubkey in signers.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+    Ok(encode_instruction(
+        token_program_id,
+        accounts,
+        TokenInstruction::RoyaltyExtension,
+        RoyaltyInstruction::UpdateRecipients,
+        &UpdateRecipientsInstructionData {
+            recipient_count: recipients.len() as u8,
+            recipients: pack_recipients(recipients)?,
+        },
+    ))
+}
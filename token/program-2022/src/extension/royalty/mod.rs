@@ -0,0 +1,261 @@
+#[cfg(feature = "serde-traits")]
+use serde::{Deserialize, Serialize};
+use {
+    crate::{
+        error::TokenError,
+        extension::{
+            transfer_fee::{TransferFeeConfig, MAX_FEE_BASIS_POINTS},
+            Extension, ExtensionType,
+        },
+    },
+    bytemuck::{Pod, Zeroable},
+    solana_program::{entrypoint::ProgramResult, pubkey::Pubkey},
+    spl_pod::{
+        optional_keys::OptionalNonZeroPubkey,
+        primitives::{PodU16, PodU64},
+    },
+};
+
+/// Instructions for the Royalty extension
+pub mod instruction;
+/// Instruction processor for the Royalty extension
+pub mod processor;
+
+/// Maximum number of royalty recipients per mint
+pub const MAX_ROYALTY_RECIPIENTS: usize = 4;
+
+/// Destination and rate of one royalty
+#[repr(C)]
+#[cfg_attr(feature = "serde-traits", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde-traits", serde(rename_all = "camelCase"))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+pub struct RoyaltyRecipient {
+    /// Token account that receives the royalty when harvested
+    pub destination: Pubkey,
+    /// Royalty, in basis points of the transfer amount
+    pub basis_points: PodU16,
+}
+
+/// Royalty extension data for mints.
+///
+/// Every transfer withholds a royalty for each recipient in the destination
+/// account, like the transfer fee, rounding down. Withheld royalties are
+/// harvested per recipient, straight into its destination account.
+///
+/// Amounts are withheld by recipient index, so when the authority updates the
+/// recipients, royalties already withheld go to the new recipient at the same
+/// index.
+#[repr(C)]
+#[cfg_attr(feature = "serde-traits", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde-traits", serde(rename_all = "camelCase"))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+pub struct RoyaltyConfig {
+    /// Authority that can update the recipients
+    pub authority: OptionalNonZeroPubkey,
+    /// Number of recipients in use
+    pub recipient_count: u8,
+    /// Recipients, in harvest index order
+    pub recipients: [RoyaltyRecipient; MAX_ROYALTY_RECIPIENTS],
+}
+impl RoyaltyConfig {
+    /// Recipients in use
+    pub fn recipients(&self) -> &[RoyaltyRecipient] {
+        &self.recipients[..self.recipient_count as usize]
+    }
+
+    /// Check that there are between one and `MAX_ROYALTY_RECIPIENTS`
+    /// recipients, and that their combined rate is at most 100%
+    pub fn check_recipients(&self) -> ProgramResult {
+        let count = self.recipient_count as usize;
+        if count == 0 || count > MAX_ROYALTY_RECIPIENTS {
+            return Err(TokenError::InvalidRoyaltyRecipients.into());
+        }
+        if self.total_basis_points() > u64::from(MAX_FEE_BASIS_POINTS) {
+            return Err(TokenError::InvalidRoyaltyRecipients.into());
+        }
+        Ok(())
+    }
+
+    /// Combined rate of the recipients, in basis points
+    pub fn total_basis_points(&self) -> u64 {
+        self.recipients()
+            .iter()
+            .map(|recipient| u64::from(u16::from(recipient.basis_points)))
+            .sum()
+    }
+
+    /// Check that the royalties plus either of the mint's transfer fees are at
+    /// most 100%, so that a transfer never withholds more than its amount
+    pub fn check_transfer_fee(&self, transfer_fee_config: &TransferFeeConfig) -> ProgramResult {
+        let older_transfer_fee = &transfer_fee_config.older_transfer_fee;
+        let newer_transfer_fee = &transfer_fee_config.newer_transfer_fee;
+        let transfer_fee_basis_points = u16::from(older_transfer_fee.transfer_fee_basis_points)
+            .max(u16::from(newer_transfer_fee.transfer_fee_basis_points));
+        if self.total_basis_points() + u64::from(transfer_fee_basis_points)
+            > u64::from(MAX_FEE_BASIS_POINTS)
+        {
+            return Err(TokenError::RoyaltyAndTransferFeeExceedMaximum.into());
+        }
+        Ok(())
+    }
+
+    /// Calculate the royalty owed to each recipient on a transfer of the given
+    /// amount
+    pub fn calculate_royalties(&self, amount: u64) -> Option<[u64; MAX_ROYALTY_RECIPIENTS]> {
+        let mut royalties = [0; MAX_ROYALTY_RECIPIENTS];
+        for (royalty, recipient) in royalties.iter_mut().zip(self.recipients()) {
+            *royalty = u128::from(amount)
+                .checked_mul(u128::from(u16::from(recipient.basis_points)))?
+                .checked_div(u128::from(MAX_FEE_BASIS_POINTS))?
+                .try_into()
+                .ok()?;
+        }
+        Some(royalties)
+    }
+}
+
+/// Withheld royalties for accounts belonging to a mint with royalties
+#[repr(C)]
+#[cfg_attr(feature = "serde-traits", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde-traits", serde(rename_all = "camelCase"))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+pub struct RoyaltyAccount {
+    /// Amount withheld for each recipient, by index
+    pub withheld_amounts: [PodU64; MAX_ROYALTY_RECIPIENTS],
+}
+impl RoyaltyAccount {
+    /// Add the royalties of a transfer into this account
+    pub fn withhold(&mut self, royalties: &[u64; MAX_ROYALTY_RECIPIENTS]) -> ProgramResult {
+        for (withheld_amount, royalty) in self.withheld_amounts.iter_mut().zip(royalties) {
+            *withheld_amount = u64::from(*withheld_amount)
+                .checked_add(*royalty)
+                .ok_or(TokenError::Overflow)?
+                .into();
+        }
+        Ok(())
+    }
+
+    /// Take the amount withheld for the recipient at the given index
+    pub fn take(&mut self, recipient_index: usize) -> u64 {
+        let withheld_amount = u64::from(self.withheld_amounts[recipient_index]);
+        self.withheld_amounts[recipient_index] = 0.into();
+        withheld_amount
+    }
+
+    /// Check if the extension is in a closable state
+    pub fn closable(&self) -> ProgramResult {
+        if self
+            .withheld_amounts
+            .iter()
+            .all(|withheld_amount| *withheld_amount == 0.into())
+        {
+            Ok(())
+        } else {
+            Err(TokenError::AccountHasWithheldTransferFees.into())
+        }
+    }
+}
+
+impl Extension for RoyaltyConfig {
+    const TYPE: ExtensionType = ExtensionType::RoyaltyConfig;
+}
+
+impl Extension for RoyaltyAccount {
+    const TYPE: ExtensionType = ExtensionType::RoyaltyAccount;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(basis_points: &[u16]) -> RoyaltyConfig {
+        let mut config = RoyaltyConfig {
+            recipient_count: basis_points.len() as u8,
+            ..RoyaltyConfig::default()
+        };
+        for (recipient, basis_points) in config.recipients.iter_mut().zip(basis_points) {
+            recipient.destination = Pubkey::new_unique();
+            recipient.basis_points = (*basis_points).into();
+        }
+        config
+    }
+
+    #[test]
+    fn check_recipients() {
+        assert!(config(&[100, 250]).check_recipients().is_ok());
+        assert!(config(&[5_000, 5_000]).check_recipients().is_ok());
+        assert_eq!(
+            config(&[]).check_recipients(),
+            Err(TokenError::InvalidRoyaltyRecipients.into())
+        );
+        assert_eq!(
+            config(&[5_000, 5_001]).check_recipients(),
+            Err(TokenError::InvalidRoyaltyRecipients.into())
+        );
+        let mut too_many = config(&[1; MAX_ROYALTY_RECIPIENTS]);
+        too_many.recipient_count += 1;
+        assert_eq!(
+            too_many.check_recipients(),
+            Err(TokenError::InvalidRoyaltyRecipients.into())
+        );
+    }
+
+    #[test]
+    fn check_transfer_fee() {
+        let mut transfer_fee_config = TransferFeeConfig::default();
+        transfer_fee_config
+            .newer_transfer_fee
+            .transfer_fee_basis_points = 7_000.into();
+        assert!(config(&[1_000, 2_000])
+            .check_transfer_fee(&transfer_fee_config)
+            .is_ok());
+        assert_eq!(
+            config(&[1_000, 2_001]).check_transfer_fee(&transfer_fee_config),
+            Err(TokenError::RoyaltyAndTransferFeeExceedMaximum.into())
+        );
+
+        // the older fee may still apply
+        transfer_fee_config
+            .older_transfer_fee
+            .transfer_fee_basis_points = 8_000.into();
+        assert_eq!(
+            config(&[1_000, 2_000]).check_transfer_fee(&transfer_fee_config),
+            Err(TokenError::RoyaltyAndTransferFeeExceedMaximum.into())
+        );
+    }
+
+    #[test]
+    fn calculate_royalties() {
+        let config = config(&[100, 250]);
+        assert_eq!(config.calculate_royalties(1_000), Some([10, 25, 0, 0]));
+        // rounds down, so the total never exceeds the amount
+        assert_eq!(config.calculate_royalties(99), Some([0, 2, 0, 0]));
+        assert_eq!(config.calculate_royalties(0), Some([0; 4]));
+        assert_eq!(
+            config.calculate_royalties(u64::MAX),
+            Some([u64::MAX / 100, u64::MAX / 40, 0, 0])
+        );
+    }
+
+    #[test]
+    fn withhold_and_take() {
+        let mut account = RoyaltyAccount::default();
+        assert!(account.closable().is_ok());
+        account.withhold(&[10, 25, 0, 0]).unwrap();
+        account.withhold(&[1, 2, 0, 0]).unwrap();
+        assert_eq!(
+            account.closable(),
+            Err(TokenError::AccountHasWithheldTransferFees.into())
+        );
+        assert_eq!(account.take(0), 11);
+        assert_eq!(account.take(0), 0);
+        assert_eq!(account.take(1), 27);
+        assert!(account.closable().is_ok());
+        assert_eq!(
+            account
+                .withhold(&[u64::MAX, 0, 0, 0])
+                .and_then(|_| account.withhold(&[1, 0, 0, 0])),
+            Err(TokenError::Overflow.into())
+        );
+    }
+}
@@ -0,0 +1,186 @@
+use {
+    crate::{
+        check_program_account,
+        error::TokenError,
+        extension::{
+            pausable::check_not_paused,
+            royalty::{
+                instruction::{
+                    HarvestInstructionData, InitializeInstructionData, RoyaltyInstruction,
+                    UpdateRecipientsInstructionData,
+                },
+                RoyaltyAccount, RoyaltyConfig,
+            },
+            snapshot::{write_checkpoint, SnapshotConfig},
+            transfer_fee::TransferFeeConfig,
+            BaseStateWithExtensions, BaseStateWithExtensionsMut, PodStateWithExtensions,
+            PodStateWithExtensionsMut,
+        },
+        instruction::{decode_instruction_data, decode_instruction_type},
+        pod::{PodAccount, PodMint},
+        processor::Processor,
+    },
+    solana_program::{
+        account_info::{next_account_info, AccountInfo},
+        entrypoint::ProgramResult,
+        msg,
+        pubkey::Pubkey,
+    },
+};
+
+fn process_initialize(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &InitializeInstructionData,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let mint_account_info = next_account_info(account_info_iter)?;
+    let mut mint_data = mint_account_info.data.borrow_mut();
+    let mut mint = PodStateWithExtensionsMut::<PodMint>::unpack_uninitialized(&mut mint_data)?;
+
+    let extension = mint.init_extension::<RoyaltyConfig>(true)?;
+    extension.authority = data.authority;
+    extension.recipient_count = data.recipient_count;
+    extension.recipients = data.recipients;
+    extension.check_recipients()
+}
+
+fn harvest_from_account<'b>(
+    mint_key: &'b Pubkey,
+    token_account_info: &'b AccountInfo<'_>,
+    recipient_index: usize,
+) -> Result<u64, TokenError> {
+    let mut token_account_data = token_account_info.data.borrow_mut();
+    let mut token_account =
+        PodStateWithExtensionsMut::<PodAccount>::unpack(&mut token_account_data)
+            .map_err(|_| TokenError::InvalidState)?;
+    if token_account.base.mint != *mint_key {
+        return Err(TokenError::MintMismatch);
+    }
+    check_program_account(token_account_info.owner).map_err(|_| TokenError::InvalidState)?;
+    let token_account_extension = token_account
+        .get_extension_mut::<RoyaltyAccount>()
+        .map_err(|_| TokenError::InvalidState)?;
+    Ok(token_account_extension.take(recipient_index))
+}
+
+fn process_harvest(accounts: &[AccountInfo], recipient_index: u8) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let mint_account_info = next_account_info(account_info_iter)?;
+    let destination_account_info = next_account_info(account_info_iter)?;
+    let token_account_infos = account_info_iter.as_slice();
+
+    check_program_account(mint_account_info.owner)?;
+    let mint_data = mint_account_info.data.borrow();
+    let mint = PodStateWithExtensions::<PodMint>::unpack(&mint_data)?;
+    check_not_paused(&mint)?;
+    let extension = mint.get_extension::<RoyaltyConfig>()?;
+    let recipient_index = recipient_index as usize;
+    let recipient = extension
+        .recipients()
+        .get(recipient_index)
+        .ok_or(TokenError::RoyaltyRecipientMismatch)?;
+    if recipient.destination != *destination_account_info.key {
+        return Err(TokenError::RoyaltyRecipientMismatch.into());
+    }
+
+    check_program_account(destination_account_info.owner)?;
+    let mut destination_account_data = destination_account_info.data.borrow_mut();
+    let mut destination_account =
+        PodStateWithExtensionsMut::<PodAccount>::unpack(&mut destination_account_data)?;
+    if destination_account.base.mint != *mint_account_info.key {
+        return Err(TokenError::MintMismatch.into());
+    }
+    if destination_account.base.is_frozen() {
+        return Err(TokenError::AccountFrozen.into());
+    }
+    if let Ok(snapshot) = mint.get_extension::<SnapshotConfig>() {
+        write_checkpoint(snapshot, &mut destination_account)?;
+    }
+    for token_account_info in token_account_infos {
+        // self-harvest, can't double-borrow the underlying data
+        let amount = if token_account_info.key == destination_account_info.key {
+            destination_account
+                .get_extension_mut::<RoyaltyAccount>()
+                .map_err(|_| TokenError::InvalidState)?
+                .take(recipient_index)
+        } else {
+            match harvest_from_account(mint_account_info.key, token_account_info, recipient_index) {
+                Ok(amount) => amount,
+                Err(e) => {
+                    msg!("Error harvesting from {}: {}", token_account_info.key, e);
+                    continue;
+                }
+            }
+        };
+        destination_account.base.amount = u64::from(destination_account.base.amount)
+            .checked_add(amount)
+            .ok_or(TokenError::Overflow)?
+            .into();
+    }
+    Ok(())
+}
+
+fn process_update_recipients(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &UpdateRecipientsInstructionData,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let mint_account_info = next_account_info(account_info_iter)?;
+    let authority_info = next_account_info(account_info_iter)?;
+    let authority_info_data_len = authority_info.data_len();
+
+    check_program_account(mint_account_info.owner)?;
+    let mut mint_data = mint_account_info.data.borrow_mut();
+    let mut mint = PodStateWithExtensionsMut::<PodMint>::unpack(&mut mint_data)?;
+    let transfer_fee_config = mint.get_extension::<TransferFeeConfig>().ok().copied();
+    let extension = mint.get_extension_mut::<RoyaltyConfig>()?;
+    let authority =
+        Option::<Pubkey>::from(extension.authority).ok_or(TokenError::NoAuthorityExists)?;
+
+    Processor::validate_owner(
+        program_id,
+        &authority,
+        authority_info,
+        authority_info_data_len,
+        account_info_iter.as_slice(),
+    )?;
+
+    // withheld amounts are tracked by index, so each one must keep a recipient
+    if data.recipient_count < extension.recipient_count {
+        return Err(TokenError::InvalidRoyaltyRecipients.into());
+    }
+    extension.recipient_count = data.recipient_count;
+    extension.recipients = data.recipients;
+    extension.check_recipients()?;
+    if let Some(transfer_fee_config) = transfer_fee_config {
+        extension.check_transfer_fee(&transfer_fee_config)?;
+    }
+    Ok(())
+}
+
+pub(crate) fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    input: &[u8],
+) -> ProgramResult {
+    check_program_account(program_id)?;
+    match decode_instruction_type(input)? {
+        RoyaltyInstruction::Initialize => {
+            msg!("RoyaltyInstruction::Initialize");
+            let data = decode_instruction_data::<InitializeInstructionData>(input)?;
+            process_initialize(program_id, accounts, data)
+        }
+        RoyaltyInstruction::Harvest => {
+            msg!("RoyaltyInstruction::Harvest");
+            let HarvestInstructionData { recipient_index } = decode_instruction_data(input)?;
+            process_harvest(accounts, *recipient_index)
+        }
+        RoyaltyInstruction::UpdateRecipients => {
+            msg!("RoyaltyInstruction::UpdateRecipients");
+            let data = decode_instruction_data::<UpdateRecipientsInstructionData>(input)?;
+            process_update_recipients(program_id, accounts, data)
+        }
+    }
+}
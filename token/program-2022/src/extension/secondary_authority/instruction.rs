@@ -0,0 +1,86 @@
+#[cfg(feature = "serde-traits")]
+use serde::{Deserialize, Serialize};
+use {
+    crate::{
+        check_program_account,
+        instruction::{encode_instruction, TokenInstruction},
+    },
+    bytemuck::{Pod, Zeroable},
+    num_enum::{IntoPrimitive, TryFromPrimitive},
+    solana_program::{
+        instruction::{AccountMeta, Instruction},
+        program_error::ProgramError,
+        pubkey::Pubkey,
+    },
+    spl_pod::{optional_keys::OptionalNonZeroPubkey, primitives::PodU64},
+    std::convert::TryInto,
+};
+
+/// Secondary authority extension instructions
+#[cfg_attr(feature = "serde-traits", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde-traits", serde(rename_all = "camelCase"))]
+#[derive(Clone, Copy, Debug, PartialEq, IntoPrimitive, TryFromPrimitive)]
+#[repr(u8)]
+pub enum SecondaryAuthorityInstruction {
+    /// Set or clear the secondary authority of a token account, and its
+    /// per-transfer limit.
+    ///
+    /// Initializes the `SecondaryAuthority` extension on the account if
+    /// needed, so the account may need to be reallocated first.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner
+    ///   0. `[writable]` The token account.
+    ///   1. `[signer]` The account's owner.
+    ///
+    ///   * Multisignature owner
+    ///   0. `[writable]` The token account.
+    ///   1. `[]` The account's multisignature owner.
+    ///   2. ..2+M `[signer]` M signer accounts.
+    ///
+    /// Data expected by this instruction:
+    ///   `crate::extension::secondary_authority::instruction::ConfigureInstructionData`
+    Configure,
+}
+
+/// Data expected by `SecondaryAuthorityInstruction::Configure`
+#[cfg_attr(feature = "serde-traits", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde-traits", serde(rename_all = "camelCase"))]
+#[derive(Clone, Copy, Pod, Zeroable)]
+#[repr(C)]
+pub struct ConfigureInstructionData {
+    /// The key that can sign limited transfers, or none to clear it
+    pub authority: OptionalNonZeroPubkey,
+    /// The maximum amount moved by a single transfer it signs
+    pub max_transfer_amount: PodU64,
+}
+
+/// Create a `Configure` instruction
+pub fn configure(
+    token_program_id: &Pubkey,
+    account: &Pubkey,
+    owner: &Pubkey,
+    signers: &[&Pubkey],
+    authority: Option<Pubkey>,
+    max_transfer_amount: u64,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    let mut accounts = vec![
+        AccountMeta::new(*account, false),
+        AccountMeta::new_readonly(*owner, signers.is_empty()),
+    ];
+    for signer_pubkey in signers.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+    Ok(encode_instruction(
+        token_program_id,
+        accounts,
+        TokenInstruction::SecondaryAuthorityExtension,
+        SecondaryAuthorityInstruction::Configure,
+        &ConfigureInstructionData {
+            authority: authority.try_into()?,
+            max_transfer_amount: max_transfer_amount.into(),
+        },
+    ))
+}
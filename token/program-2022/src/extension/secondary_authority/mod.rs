@@ -0,0 +1,46 @@
+#[cfg(feature = "serde-traits")]
+use serde::{Deserialize, Serialize};
+use {
+    crate::extension::{BaseState, BaseStateWithExtensions, Extension, ExtensionType},
+    bytemuck::{Pod, Zeroable},
+    solana_program::pubkey::Pubkey,
+    spl_pod::{optional_keys::OptionalNonZeroPubkey, primitives::PodU64},
+};
+
+/// Instructions for the SecondaryAuthority extension
+pub mod instruction;
+/// Instruction processor for the SecondaryAuthority extension
+pub mod processor;
+
+/// Secondary authority extension data for accounts.
+///
+/// The secondary authority may sign transfers of up to `max_transfer_amount`
+/// tokens out of the account. It cannot approve, burn, close, or change any
+/// account settings, all of which still require the owner.
+#[repr(C)]
+#[cfg_attr(feature = "serde-traits", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde-traits", serde(rename_all = "camelCase"))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+pub struct SecondaryAuthority {
+    /// Key that can sign limited transfers, if any
+    pub authority: OptionalNonZeroPubkey,
+    /// Maximum amount of tokens moved by a single transfer signed by the
+    /// secondary authority
+    pub max_transfer_amount: PodU64,
+}
+impl Extension for SecondaryAuthority {
+    const TYPE: ExtensionType = ExtensionType::SecondaryAuthority;
+}
+
+/// Get the secondary authority and its transfer limit, if the account has one
+pub fn get_secondary_authority<BSE: BaseStateWithExtensions<S>, S: BaseState>(
+    account_state: &BSE,
+) -> Option<(Pubkey, u64)> {
+    account_state
+        .get_extension::<SecondaryAuthority>()
+        .ok()
+        .and_then(|extension| {
+            Option::<Pubkey>::from(extension.authority)
+                .map(|authority| (authority, u64::from(extension.max_transfer_amount)))
+        })
+}
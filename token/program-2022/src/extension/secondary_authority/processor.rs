@@ -0,0 +1,83 @@
+use {
+    crate::{
+        check_program_account,
+        error::TokenError,
+        extension::{
+            cpi_guard::{in_cpi, CpiGuard},
+            secondary_authority::{
+                instruction::{ConfigureInstructionData, SecondaryAuthorityInstruction},
+                SecondaryAuthority,
+            },
+            BaseStateWithExtensions, BaseStateWithExtensionsMut, PodStateWithExtensionsMut,
+        },
+        instruction::{decode_instruction_data, decode_instruction_type},
+        pod::PodAccount,
+        processor::Processor,
+    },
+    solana_program::{
+        account_info::{next_account_info, AccountInfo},
+        entrypoint::ProgramResult,
+        msg,
+        pubkey::Pubkey,
+    },
+    spl_pod::{optional_keys::OptionalNonZeroPubkey, primitives::PodU64},
+};
+
+fn process_configure(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    authority: &OptionalNonZeroPubkey,
+    max_transfer_amount: &PodU64,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let token_account_info = next_account_info(account_info_iter)?;
+    let owner_info = next_account_info(account_info_iter)?;
+    let owner_info_data_len = owner_info.data_len();
+
+    let mut account_data = token_account_info.data.borrow_mut();
+    let mut account = PodStateWithExtensionsMut::<PodAccount>::unpack(&mut account_data)?;
+    if account.base.is_frozen() {
+        return Err(TokenError::AccountFrozen.into());
+    }
+
+    Processor::validate_owner(
+        program_id,
+        &account.base.owner,
+        owner_info,
+        owner_info_data_len,
+        account_info_iter.as_slice(),
+    )?;
+
+    if let Ok(cpi_guard) = account.get_extension::<CpiGuard>() {
+        if cpi_guard.lock_cpi.into() && in_cpi() {
+            return Err(TokenError::CpiGuardApproveBlocked.into());
+        }
+    }
+
+    let extension = if let Ok(extension) = account.get_extension_mut::<SecondaryAuthority>() {
+        extension
+    } else {
+        account.init_extension::<SecondaryAuthority>(true)?
+    };
+    extension.authority = *authority;
+    extension.max_transfer_amount = *max_transfer_amount;
+    Ok(())
+}
+
+pub(crate) fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    input: &[u8],
+) -> ProgramResult {
+    check_program_account(program_id)?;
+    match decode_instruction_type(input)? {
+        SecondaryAuthorityInstruction::Configure => {
+            msg!("SecondaryAuthorityInstruction::Configure");
+            let ConfigureInstructionData {
+                authority,
+                max_transfer_amount,
+            } = decode_instruction_data(input)?;
+            process_configure(program_id, accounts, authority, max_transfer_amount)
+        }
+    }
+}
@@ -0,0 +1,157 @@
+#[cfg(feature = "serde-traits")]
+use serde::{Deserialize, Serialize};
+use {
+    crate::{
+        check_program_account,
+        instruction::{encode_instruction, TokenInstruction},
+    },
+    bytemuck::{Pod, Zeroable},
+    num_enum::{IntoPrimitive, TryFromPrimitive},
+    solana_program::{
+        instruction::{AccountMeta, Instruction},
+        program_error::ProgramError,
+        pubkey::Pubkey,
+    },
+    spl_pod::{optional_keys::OptionalNonZeroPubkey, primitives::PodU64},
+    std::convert::TryInto,
+};
+
+/// Snapshot extension instructions
+#[cfg_attr(feature = "serde-traits", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde-traits", serde(rename_all = "camelCase"))]
+#[derive(Clone, Copy, Debug, PartialEq, IntoPrimitive, TryFromPrimitive)]
+#[repr(u8)]
+pub enum SnapshotInstruction {
+    /// Initialize a new mint with balance snapshots.
+    ///
+    /// Fails if the mint has already been initialized, so must be called before
+    /// `InitializeMint`.
+    ///
+    /// The mint must have exactly enough space allocated for the base mint (82
+    /// bytes), plus 83 bytes of padding, 1 byte reserved for the account type,
+    /// then space required for this extension, plus any others.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` The mint to initialize.
+    ///
+    /// Data expected by this instruction:
+    ///   `crate::extension::snapshot::instruction::InitializeInstructionData`
+    Initialize,
+    /// Create a new snapshot of all balances. Only supported for mints that
+    /// include the `SnapshotConfig` extension.
+    ///
+    /// The new snapshot id is set as the instruction's return data, as a
+    /// little-endian `u64`.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single authority
+    ///   0. `[writable]` The mint.
+    ///   1. `[signer]` The snapshot authority.
+    ///
+    ///   * Multisignature authority
+    ///   0. `[writable]` The mint.
+    ///   1. `[]` The mint's multisignature snapshot authority.
+    ///   2. ..2+M `[signer]` M signer accounts.
+    CreateSnapshot,
+    /// Get the balance of a token account as of a snapshot.
+    ///
+    /// The balance is set as the instruction's return data, as a
+    /// little-endian `u64`.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[]` The token account.
+    ///   1. `[]` The token mint.
+    ///
+    /// Data expected by this instruction:
+    ///   `crate::extension::snapshot::instruction::GetBalanceAtInstructionData`
+    GetBalanceAt,
+}
+
+/// Data expected by `SnapshotInstruction::Initialize`
+#[cfg_attr(feature = "serde-traits", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde-traits", serde(rename_all = "camelCase"))]
+#[derive(Clone, Copy, Pod, Zeroable)]
+#[repr(C)]
+pub struct InitializeInstructionData {
+    /// The public key for the account that can create snapshots
+    pub authority: OptionalNonZeroPubkey,
+}
+
+/// Data expected by `SnapshotInstruction::GetBalanceAt`
+#[cfg_attr(feature = "serde-traits", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde-traits", serde(rename_all = "camelCase"))]
+#[derive(Clone, Copy, Pod, Zeroable)]
+#[repr(C)]
+pub struct GetBalanceAtInstructionData {
+    /// The snapshot id
+    pub snapshot_id: PodU64,
+}
+
+/// Create an `Initialize` instruction
+pub fn initialize(
+    token_program_id: &Pubkey,
+    mint: &Pubkey,
+    authority: Option<Pubkey>,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    let accounts = vec![AccountMeta::new(*mint, false)];
+    Ok(encode_instruction(
+        token_program_id,
+        accounts,
+        TokenInstruction::SnapshotExtension,
+        SnapshotInstruction::Initialize,
+        &InitializeInstructionData {
+            authority: authority.try_into()?,
+        },
+    ))
+}
+
+/// Create a `CreateSnapshot` instruction
+pub fn create_snapshot(
+    token_program_id: &Pubkey,
+    mint: &Pubkey,
+    authority: &Pubkey,
+    signers: &[&Pubkey],
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    let mut accounts = vec![
+        AccountMeta::new(*mint, false),
+        AccountMeta::new_readonly(*authority, signers.is_empty()),
+    ];
+    for signer_pubkey in signers.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+    Ok(encode_instruction(
+        token_program_id,
+        accounts,
+        TokenInstruction::SnapshotExtension,
+        SnapshotInstruction::CreateSnapshot,
+        &(),
+    ))
+}
+
+/// Create a `GetBalanceAt` instruction
+pub fn get_balance_at(
+    token_program_id: &Pubkey,
+    account: &Pubkey,
+    mint: &Pubkey,
+    snapshot_id: u64,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    let accounts = vec![
+        AccountMeta::new_readonly(*account, false),
+        AccountMeta::new_readonly(*mint, false),
+    ];
+    Ok(encode_instruction(
+        token_program_id,
+        accounts,
+        TokenInstruction::SnapshotExtension,
+        SnapshotInstruction::GetBalanceAt,
+        &GetBalanceAtInstructionData {
+            snapshot_id: snapshot_id.into(),
+        },
+    ))
+}
@@ -0,0 +1,198 @@
+#[cfg(feature = "serde-traits")]
+use serde::{Deserialize, Serialize};
+use {
+    crate::{
+        error::TokenError,
+        extension::{
+            BaseStateWithExtensionsMut, Extension, ExtensionType, PodStateWithExtensionsMut,
+        },
+        pod::PodAccount,
+    },
+    bytemuck::{Pod, Zeroable},
+    solana_program::{entrypoint::ProgramResult, program_error::ProgramError},
+    spl_pod::{optional_keys::OptionalNonZeroPubkey, primitives::PodU64},
+};
+
+/// Instructions for the Snapshot extension
+pub mod instruction;
+/// Instruction processor for the Snapshot extension
+pub mod processor;
+
+/// Number of checkpoints retained by each account. Once an account has
+/// recorded more, balances at snapshots older than its oldest retained
+/// checkpoint fail with `SnapshotCheckpointEvicted`.
+pub const MAX_CHECKPOINTS: usize = 8;
+
+/// Snapshot extension data for mints.
+#[repr(C)]
+#[cfg_attr(feature = "serde-traits", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde-traits", serde(rename_all = "camelCase"))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+pub struct SnapshotConfig {
+    /// Authority that can create snapshots
+    pub authority: OptionalNonZeroPubkey,
+    /// Id of the latest snapshot, or 0 if none has been created
+    pub snapshot_id: PodU64,
+}
+
+/// Balance of an account as of a snapshot
+#[repr(C)]
+#[cfg_attr(feature = "serde-traits", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde-traits", serde(rename_all = "camelCase"))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+pub struct Checkpoint {
+    /// Snapshot id
+    pub snapshot_id: PodU64,
+    /// Balance of the account when the snapshot was created
+    pub amount: PodU64,
+}
+
+/// Balance checkpoints for accounts belonging to a mint with snapshots.
+///
+/// A checkpoint is written on the first balance change after each snapshot,
+/// so a snapshot with no checkpoint at or after it saw the current balance.
+/// Only the latest `MAX_CHECKPOINTS` checkpoints are retained.
+#[repr(C)]
+#[cfg_attr(feature = "serde-traits", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde-traits", serde(rename_all = "camelCase"))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+pub struct SnapshotAccount {
+    /// Oldest snapshot id whose balance can still be queried
+    pub first_available_snapshot_id: PodU64,
+    /// Number of checkpoints in use
+    pub checkpoint_count: u8,
+    /// Checkpoints, in increasing snapshot id order
+    pub checkpoints: [Checkpoint; MAX_CHECKPOINTS],
+}
+impl SnapshotAccount {
+    /// Record the account's balance for the given snapshot, ahead of a change
+    /// to that balance. Only the first change after a snapshot is recorded.
+    pub fn record(&mut self, snapshot_id: u64, amount: u64) {
+        if snapshot_id == 0 {
+            return;
+        }
+        let count = self.checkpoint_count as usize;
+        if count > 0 && u64::from(self.checkpoints[count - 1].snapshot_id) >= snapshot_id {
+            return;
+        }
+        let checkpoint = Checkpoint {
+            snapshot_id: snapshot_id.into(),
+            amount: amount.into(),
+        };
+        if count == MAX_CHECKPOINTS {
+            let dropped_snapshot_id = u64::from(self.checkpoints[0].snapshot_id);
+            self.first_available_snapshot_id = dropped_snapshot_id.saturating_add(1).into();
+            self.checkpoints.copy_within(1.., 0);
+            self.checkpoints[MAX_CHECKPOINTS - 1] = checkpoint;
+        } else {
+            self.checkpoints[count] = checkpoint;
+            self.checkpoint_count = self.checkpoint_count.saturating_add(1);
+        }
+    }
+
+    /// Get the account's balance as of the given snapshot, given the mint's
+    /// latest snapshot id and the account's current balance.
+    ///
+    /// Fails with `SnapshotNotAvailable` if the snapshot does not exist, or
+    /// `SnapshotCheckpointEvicted` if its checkpoint was dropped to make room
+    /// for newer ones.
+    pub fn balance_at(
+        &self,
+        snapshot_id: u64,
+        latest_snapshot_id: u64,
+        amount: u64,
+    ) -> Result<u64, ProgramError> {
+        if snapshot_id == 0 || snapshot_id > latest_snapshot_id {
+            return Err(TokenError::SnapshotNotAvailable.into());
+        }
+        if snapshot_id < u64::from(self.first_available_snapshot_id) {
+            return Err(TokenError::SnapshotCheckpointEvicted.into());
+        }
+        let count = self.checkpoint_count as usize;
+        Ok(self.checkpoints[..count]
+            .iter()
+            .find(|checkpoint| u64::from(checkpoint.snapshot_id) >= snapshot_id)
+            .map(|checkpoint| u64::from(checkpoint.amount))
+            .unwrap_or(amount))
+    }
+}
+
+impl Extension for SnapshotConfig {
+    const TYPE: ExtensionType = ExtensionType::SnapshotConfig;
+}
+
+impl Extension for SnapshotAccount {
+    const TYPE: ExtensionType = ExtensionType::SnapshotAccount;
+}
+
+/// Write a checkpoint of the account's balance for the mint's latest
+/// snapshot. Must be called before the balance changes.
+pub fn write_checkpoint(
+    config: &SnapshotConfig,
+    account: &mut PodStateWithExtensionsMut<PodAccount>,
+) -> ProgramResult {
+    let amount = u64::from(account.base.amount);
+    account
+        .get_extension_mut::<SnapshotAccount>()?
+        .record(config.snapshot_id.into(), amount);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn balance_without_checkpoints() {
+        let account = SnapshotAccount::default();
+        assert_eq!(account.balance_at(1, 2, 100).unwrap(), 100);
+        assert_eq!(account.balance_at(2, 2, 100).unwrap(), 100);
+        assert_eq!(
+            account.balance_at(0, 2, 100),
+            Err(TokenError::SnapshotNotAvailable.into())
+        );
+        assert_eq!(
+            account.balance_at(3, 2, 100),
+            Err(TokenError::SnapshotNotAvailable.into())
+        );
+    }
+
+    #[test]
+    fn record() {
+        let mut account = SnapshotAccount::default();
+        // no snapshot yet, nothing to record
+        account.record(0, 10);
+        assert_eq!(account, SnapshotAccount::default());
+
+        // balance was 10 at snapshot 1, later changes are not recorded
+        account.record(1, 10);
+        account.record(1, 20);
+        assert_eq!(account.checkpoint_count, 1);
+
+        // no change between snapshots 2 and 3, balance was 30 at both
+        account.record(3, 30);
+        assert_eq!(account.checkpoint_count, 2);
+
+        assert_eq!(account.balance_at(1, 4, 40).unwrap(), 10);
+        assert_eq!(account.balance_at(2, 4, 40).unwrap(), 30);
+        assert_eq!(account.balance_at(3, 4, 40).unwrap(), 30);
+        assert_eq!(account.balance_at(4, 4, 40).unwrap(), 40);
+    }
+
+    #[test]
+    fn oldest_checkpoints_dropped() {
+        let mut account = SnapshotAccount::default();
+        for snapshot_id in 1..=MAX_CHECKPOINTS as u64 + 2 {
+            account.record(snapshot_id, snapshot_id * 10);
+        }
+        let latest = MAX_CHECKPOINTS as u64 + 2;
+        assert_eq!(account.checkpoint_count as usize, MAX_CHECKPOINTS);
+        assert_eq!(u64::from(account.first_available_snapshot_id), 3);
+        assert_eq!(
+            account.balance_at(2, latest, 0),
+            Err(TokenError::SnapshotCheckpointEvicted.into())
+        );
+        assert_eq!(account.balance_at(3, latest, 0).unwrap(), 30);
+        assert_eq!(account.balance_at(latest, latest, 0).unwrap(), latest * 10);
+    }
+}
@@ -0,0 +1,123 @@
+use {
+    crate::{
+        check_program_account,
+        error::TokenError,
+        extension::{
+            snapshot::{
+                instruction::{
+                    GetBalanceAtInstructionData, InitializeInstructionData, SnapshotInstruction,
+                },
+                SnapshotAccount, SnapshotConfig,
+            },
+            BaseStateWithExtensions, BaseStateWithExtensionsMut, PodStateWithExtensions,
+            PodStateWithExtensionsMut,
+        },
+        instruction::{decode_instruction_data, decode_instruction_type},
+        pod::{PodAccount, PodMint},
+        processor::Processor,
+    },
+    solana_program::{
+        account_info::{next_account_info, AccountInfo},
+        entrypoint::ProgramResult,
+        msg,
+        program::set_return_data,
+        pubkey::Pubkey,
+    },
+    spl_pod::optional_keys::OptionalNonZeroPubkey,
+};
+
+fn process_initialize(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    authority: &OptionalNonZeroPubkey,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let mint_account_info = next_account_info(account_info_iter)?;
+    let mut mint_data = mint_account_info.data.borrow_mut();
+    let mut mint = PodStateWithExtensionsMut::<PodMint>::unpack_uninitialized(&mut mint_data)?;
+
+    let extension = mint.init_extension::<SnapshotConfig>(true)?;
+    extension.authority = *authority;
+    Ok(())
+}
+
+fn process_create_snapshot(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let mint_account_info = next_account_info(account_info_iter)?;
+    let owner_info = next_account_info(account_info_iter)?;
+    let owner_info_data_len = owner_info.data_len();
+
+    let mut mint_data = mint_account_info.data.borrow_mut();
+    let mut mint = PodStateWithExtensionsMut::<PodMint>::unpack(&mut mint_data)?;
+    let extension = mint.get_extension_mut::<SnapshotConfig>()?;
+    let authority =
+        Option::<Pubkey>::from(extension.authority).ok_or(TokenError::NoAuthorityExists)?;
+
+    Processor::validate_owner(
+        program_id,
+        &authority,
+        owner_info,
+        owner_info_data_len,
+        account_info_iter.as_slice(),
+    )?;
+
+    let snapshot_id = u64::from(extension.snapshot_id)
+        .checked_add(1)
+        .ok_or(TokenError::Overflow)?;
+    extension.snapshot_id = snapshot_id.into();
+    msg!("Snapshot {}", snapshot_id);
+    set_return_data(&snapshot_id.to_le_bytes());
+    Ok(())
+}
+
+fn process_get_balance_at(accounts: &[AccountInfo], snapshot_id: u64) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let token_account_info = next_account_info(account_info_iter)?;
+    let mint_info = next_account_info(account_info_iter)?;
+
+    check_program_account(token_account_info.owner)?;
+    let token_account_data = token_account_info.data.borrow();
+    let token_account = PodStateWithExtensions::<PodAccount>::unpack(&token_account_data)?;
+    if token_account.base.mint != *mint_info.key {
+        return Err(TokenError::MintMismatch.into());
+    }
+
+    check_program_account(mint_info.owner)?;
+    let mint_data = mint_info.data.borrow();
+    let mint = PodStateWithExtensions::<PodMint>::unpack(&mint_data)?;
+    let config = mint.get_extension::<SnapshotConfig>()?;
+
+    let amount = token_account
+        .get_extension::<SnapshotAccount>()?
+        .balance_at(
+            snapshot_id,
+            config.snapshot_id.into(),
+            token_account.base.amount.into(),
+        )?;
+    set_return_data(&amount.to_le_bytes());
+    Ok(())
+}
+
+pub(crate) fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    input: &[u8],
+) -> ProgramResult {
+    check_program_account(program_id)?;
+    match decode_instruction_type(input)? {
+        SnapshotInstruction::Initialize => {
+            msg!("SnapshotInstruction::Initialize");
+            let InitializeInstructionData { authority } = decode_instruction_data(input)?;
+            process_initialize(program_id, accounts, authority)
+        }
+        SnapshotInstruction::CreateSnapshot => {
+            msg!("SnapshotInstruction::CreateSnapshot");
+            process_create_snapshot(program_id, accounts)
+        }
+        SnapshotInstruction::GetBalanceAt => {
+            msg!("SnapshotInstruction::GetBalanceAt");
+            let GetBalanceAtInstructionData { snapshot_id } = decode_instruction_data(input)?;
+            process_get_balance_at(accounts, u64::from(*snapshot_id))
+        }
+    }
+}
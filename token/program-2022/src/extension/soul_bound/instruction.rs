@@ -0,0 +1,153 @@
+#[cfg(feature = "serde-traits")]
+use serde::{Deserialize, Serialize};
+use {
+    crate::{
+        check_program_account,
+        instruction::{encode_instruction, TokenInstruction},
+    },
+    bytemuck::{Pod, Zeroable},
+    num_enum::{IntoPrimitive, TryFromPrimitive},
+    solana_program::{
+        instruction::{AccountMeta, Instruction},
+        program_error::ProgramError,
+        pubkey::Pubkey,
+    },
+    spl_pod::optional_keys::OptionalNonZeroPubkey,
+    std::convert::TryInto,
+};
+
+/// Soul-bound extension instructions
+#[cfg_attr(feature = "serde-traits", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde-traits", serde(rename_all = "camelCase"))]
+#[derive(Clone, Copy, Debug, PartialEq, IntoPrimitive, TryFromPrimitive)]
+#[repr(u8)]
+pub enum SoulBoundInstruction {
+    /// Initialize a new mint whose tokens can only move by account recovery.
+    ///
+    /// Fails if the mint has already been initialized, so must be called before
+    /// `InitializeMint`. The mint may not also include any extension applied
+    /// on transfer, such as `TransferFeeConfig` or `RoyaltyConfig`.
+    ///
+    /// The mint must have exactly enough space allocated for the base mint (82
+    /// bytes), plus 83 bytes of padding, 1 byte reserved for the account type,
+    /// then space required for this extension, plus any others.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` The mint to initialize.
+    Initialize,
+    /// Set or clear the owner that an account's tokens may be recovered to.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner
+    ///   0. `[writable]` The token account.
+    ///   1. `[signer]` The account's owner.
+    ///
+    ///   * Multisignature owner
+    ///   0. `[writable]` The token account.
+    ///   1. `[]` The account's multisignature owner.
+    ///   2. ..2+M `[signer]` M signer accounts.
+    ///
+    /// Data expected by this instruction:
+    ///   `crate::extension::soul_bound::instruction::SetRecoveryAddressInstructionData`
+    SetRecoveryAddress,
+    /// Move the whole balance of an account to an account owned by its
+    /// recovery address. Both the owner and the recovery address must
+    /// sign, either of which may be a multisig.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` The account to recover.
+    ///   1. `[]` The token mint.
+    ///   2. `[writable]` The destination account, owned by the recovery
+    ///      address.
+    ///   3. `[signer]` The owner of the account to recover, or `[]` if it is a
+    ///      multisig.
+    ///   4. `[signer]` The recovery address, or `[]` if it is a multisig.
+    ///   5. ..5+M `[signer]` M signer accounts for the multisig owner and
+    ///      recovery address.
+    RecoverAccount,
+}
+
+/// Data expected by `SoulBoundInstruction::SetRecoveryAddress`
+#[cfg_attr(feature = "serde-traits", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde-traits", serde(rename_all = "camelCase"))]
+#[derive(Clone, Copy, Pod, Zeroable)]
+#[repr(C)]
+pub struct SetRecoveryAddressInstructionData {
+    /// The owner that tokens may be recovered to, or none to clear it
+    pub recovery_address: OptionalNonZeroPubkey,
+}
+
+/// Create an `Initialize` instruction
+pub fn initialize(token_program_id: &Pubkey, mint: &Pubkey) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    let accounts = vec![AccountMeta::new(*mint, false)];
+    Ok(encode_instruction(
+        token_program_id,
+        accounts,
+        TokenInstruction::SoulBoundExtension,
+        SoulBoundInstruction::Initialize,
+        &(),
+    ))
+}
+
+/// Create a `SetRecoveryAddress` instruction
+pub fn set_recovery_address(
+    token_program_id: &Pubkey,
+    account: &Pubkey,
+    owner: &Pubkey,
+    signers: &[&Pubkey],
+    recovery_address: Option<Pubkey>,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    let mut accounts = vec![
+        AccountMeta::new(*account, false),
+        AccountMeta::new_readonly(*owner, signers.is_empty()),
+    ];
+    for signer_pubkey in signers.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+    Ok(encode_instruction(
+        token_program_id,
+        accounts,
+        TokenInstruction::SoulBoundExtension,
+        SoulBoundInstruction::SetRecoveryAddress,
+        &SetRecoveryAddressInstructionData {
+            recovery_address: recovery_address.try_into()?,
+        },
+    ))
+}
+
+/// Create a `RecoverAccount` instruction
+#[allow(clippy::too_many_arguments)]
+pub fn recover_account(
+    token_program_id: &Pubkey,
+    account: &Pubkey,
+    mint: &Pubkey,
+    destination: &Pubkey,
+    owner: &Pubkey,
+    owner_signers: &[&Pubkey],
+    recovery_address: &Pubkey,
+    recovery_address_signers: &[&Pubkey],
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    let mut accounts = vec![
+        AccountMeta::new(*account, false),
+        AccountMeta::new_readonly(*mint, false),
+        AccountMeta::new(*destination, false),
+        AccountMeta::new_readonly(*owner, owner_signers.is_empty()),
+        AccountMeta::new_readonly(*recovery_address, recovery_address_signers.is_empty()),
+    ];
+    for signer_pubkey in owner_signers.iter().chain(recovery_address_signers.iter()) {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+    Ok(encode_instruction(
+        token_program_id,
+        accounts,
+        TokenInstruction::SoulBoundExtension,
+        SoulBoundInstruction::RecoverAccount,
+        &(),
+    ))
+}
@@ -0,0 +1,38 @@
+#[cfg(feature = "serde-traits")]
+use serde::{Deserialize, Serialize};
+use {
+    crate::extension::{Extension, ExtensionType},
+    bytemuck::{Pod, Zeroable},
+    spl_pod::optional_keys::OptionalNonZeroPubkey,
+};
+
+/// Instructions for the SoulBound extension
+pub mod instruction;
+/// Instruction processor for the SoulBound extension
+pub mod processor;
+
+/// Indicates that the tokens from this mint can't be transferred, except by
+/// recovering an account to its registered recovery address
+#[cfg_attr(feature = "serde-traits", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde-traits", serde(rename_all = "camelCase"))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+#[repr(transparent)]
+pub struct SoulBound;
+
+/// Recovery address for accounts belonging to a soul-bound mint
+#[repr(C)]
+#[cfg_attr(feature = "serde-traits", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde-traits", serde(rename_all = "camelCase"))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+pub struct SoulBoundAccount {
+    /// Owner that the account's tokens may be recovered to
+    pub recovery_address: OptionalNonZeroPubkey,
+}
+
+impl Extension for SoulBound {
+    const TYPE: ExtensionType = ExtensionType::SoulBound;
+}
+
+impl Extension for SoulBoundAccount {
+    const TYPE: ExtensionType = ExtensionType::SoulBoundAccount;
+}
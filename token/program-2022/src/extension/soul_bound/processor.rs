@@ -0,0 +1,180 @@
+use {
+    crate::{
+        check_program_account,
+        error::TokenError,
+        extension::{
+            cpi_guard::{in_cpi, CpiGuard},
+            pausable::check_not_paused,
+            snapshot::{write_checkpoint, SnapshotConfig},
+            soul_bound::{
+                instruction::{SetRecoveryAddressInstructionData, SoulBoundInstruction},
+                SoulBound, SoulBoundAccount,
+            },
+            BaseStateWithExtensions, BaseStateWithExtensionsMut, PodStateWithExtensions,
+            PodStateWithExtensionsMut,
+        },
+        instruction::{decode_instruction_data, decode_instruction_type},
+        pod::{PodAccount, PodCOption, PodMint},
+        processor::Processor,
+    },
+    solana_program::{
+        account_info::{next_account_info, AccountInfo},
+        entrypoint::ProgramResult,
+        msg,
+        pubkey::Pubkey,
+    },
+    spl_pod::optional_keys::OptionalNonZeroPubkey,
+};
+
+fn process_initialize(accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let mint_account_info = next_account_info(account_info_iter)?;
+    let mut mint_data = mint_account_info.data.borrow_mut();
+    let mut mint = PodStateWithExtensionsMut::<PodMint>::unpack_uninitialized(&mut mint_data)?;
+
+    mint.init_extension::<SoulBound>(true)?;
+    Ok(())
+}
+
+fn process_set_recovery_address(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    recovery_address: &OptionalNonZeroPubkey,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let token_account_info = next_account_info(account_info_iter)?;
+    let owner_info = next_account_info(account_info_iter)?;
+    let owner_info_data_len = owner_info.data_len();
+
+    let mut account_data = token_account_info.data.borrow_mut();
+    let mut account = PodStateWithExtensionsMut::<PodAccount>::unpack(&mut account_data)?;
+    if account.base.is_frozen() {
+        return Err(TokenError::AccountFrozen.into());
+    }
+
+    Processor::validate_owner(
+        program_id,
+        &account.base.owner,
+        owner_info,
+        owner_info_data_len,
+        account_info_iter.as_slice(),
+    )?;
+
+    if let Ok(cpi_guard) = account.get_extension::<CpiGuard>() {
+        if cpi_guard.lock_cpi.into() && in_cpi() {
+            return Err(TokenError::CpiGuardSetAuthorityBlocked.into());
+        }
+    }
+
+    let extension = account.get_extension_mut::<SoulBoundAccount>()?;
+    extension.recovery_address = *recovery_address;
+    Ok(())
+}
+
+fn process_recover_account(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let source_account_info = next_account_info(account_info_iter)?;
+    let mint_info = next_account_info(account_info_iter)?;
+    let destination_account_info = next_account_info(account_info_iter)?;
+    let owner_info = next_account_info(account_info_iter)?;
+    let owner_info_data_len = owner_info.data_len();
+    let recovery_address_info = next_account_info(account_info_iter)?;
+    let recovery_address_info_data_len = recovery_address_info.data_len();
+
+    let mut source_account_data = source_account_info.data.borrow_mut();
+    let mut source_account =
+        PodStateWithExtensionsMut::<PodAccount>::unpack(&mut source_account_data)?;
+    if source_account.base.mint != *mint_info.key {
+        return Err(TokenError::MintMismatch.into());
+    }
+    if source_account.base.is_frozen() {
+        return Err(TokenError::AccountFrozen.into());
+    }
+    let recovery_address = Option::<Pubkey>::from(
+        source_account
+            .get_extension::<SoulBoundAccount>()?
+            .recovery_address,
+    )
+    .ok_or(TokenError::RecoveryAddressMismatch)?;
+
+    Processor::validate_owner(
+        program_id,
+        &source_account.base.owner,
+        owner_info,
+        owner_info_data_len,
+        account_info_iter.as_slice(),
+    )?;
+    Processor::validate_owner(
+        program_id,
+        &recovery_address,
+        recovery_address_info,
+        recovery_address_info_data_len,
+        account_info_iter.as_slice(),
+    )?;
+
+    check_program_account(source_account_info.owner)?;
+    check_program_account(destination_account_info.owner)?;
+
+    // This check MUST occur just before the amounts are manipulated
+    // to ensure self-recoveries are fully validated
+    if source_account_info.key == destination_account_info.key {
+        return Ok(());
+    }
+
+    let mut destination_account_data = destination_account_info.data.borrow_mut();
+    let mut destination_account =
+        PodStateWithExtensionsMut::<PodAccount>::unpack(&mut destination_account_data)?;
+    if destination_account.base.mint != *mint_info.key {
+        return Err(TokenError::MintMismatch.into());
+    }
+    if destination_account.base.is_frozen() {
+        return Err(TokenError::AccountFrozen.into());
+    }
+    if destination_account.base.owner != recovery_address {
+        return Err(TokenError::RecoveryAddressMismatch.into());
+    }
+
+    check_program_account(mint_info.owner)?;
+    let mint_data = mint_info.data.borrow();
+    let mint = PodStateWithExtensions::<PodMint>::unpack(&mint_data)?;
+    mint.get_extension::<SoulBound>()?;
+    check_not_paused(&mint)?;
+    if let Ok(snapshot) = mint.get_extension::<SnapshotConfig>() {
+        write_checkpoint(snapshot, &mut source_account)?;
+        write_checkpoint(snapshot, &mut destination_account)?;
+    }
+
+    let amount = u64::from(source_account.base.amount);
+    source_account.base.amount = 0.into();
+    source_account.base.delegate = PodCOption::none();
+    source_account.base.delegated_amount = 0.into();
+    destination_account.base.amount = u64::from(destination_account.base.amount)
+        .checked_add(amount)
+        .ok_or(TokenError::Overflow)?
+        .into();
+    Ok(())
+}
+
+pub(crate) fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    input: &[u8],
+) -> ProgramResult {
+    check_program_account(program_id)?;
+    match decode_instruction_type(input)? {
+        SoulBoundInstruction::Initialize => {
+            msg!("SoulBoundInstruction::Initialize");
+            process_initialize(accounts)
+        }
+        SoulBoundInstruction::SetRecoveryAddress => {
+            msg!("SoulBoundInstruction::SetRecoveryAddress");
+            let SetRecoveryAddressInstructionData { recovery_address } =
+                decode_instruction_data(input)?;
+            process_set_recovery_address(program_id, accounts, recovery_address)
+        }
+        SoulBoundInstruction::RecoverAccount => {
+            msg!("SoulBoundInstruction::RecoverAccount");
+            process_recover_account(program_id, accounts)
+        }
+    }
+}
@@ -0,0 +1,106 @@
+#[cfg(feature = "serde-traits")]
+use serde::{Deserialize, Serialize};
+use {
+    crate::{
+        check_program_account,
+        extension::interest_bearing_mint::UnixTimestamp,
+        instruction::{encode_instruction, TokenInstruction},
+    },
+    bytemuck::{Pod, Zeroable},
+    num_enum::{IntoPrimitive, TryFromPrimitive},
+    solana_program::{
+        instruction::{AccountMeta, Instruction},
+        program_error::ProgramError,
+        pubkey::Pubkey,
+    },
+};
+
+/// Timed freeze extension instructions
+#[cfg_attr(feature = "serde-traits", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde-traits", serde(rename_all = "camelCase"))]
+#[derive(Clone, Copy, Debug, PartialEq, IntoPrimitive, TryFromPrimitive)]
+#[repr(u8)]
+pub enum TimedFreezeInstruction {
+    /// Freeze an account, like `FreezeAccount`, until the given unix
+    /// timestamp. From then on, anyone may thaw it with `Thaw`. The freeze
+    /// authority may still thaw it earlier with `ThawAccount`.
+    ///
+    /// Initializes the `TimedFreeze` extension on the account if needed, so
+    /// the account may need to be reallocated first.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single authority
+    ///   0. `[writable]` The account to freeze.
+    ///   1. `[]` The token mint.
+    ///   2. `[signer]` The mint freeze authority.
+    ///
+    ///   * Multisignature authority
+    ///   0. `[writable]` The account to freeze.
+    ///   1. `[]` The token mint.
+    ///   2. `[]` The mint's multisignature freeze authority.
+    ///   3. ..3+M `[signer]` M signer accounts.
+    ///
+    /// Data expected by this instruction:
+    ///   `crate::extension::timed_freeze::instruction::FreezeUntilInstructionData`
+    FreezeUntil,
+    /// Permissionless instruction to thaw an account frozen with
+    /// `FreezeUntil`, once its timestamp has passed.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` The account to thaw.
+    Thaw,
+}
+
+/// Data expected by `TimedFreezeInstruction::FreezeUntil`
+#[cfg_attr(feature = "serde-traits", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde-traits", serde(rename_all = "camelCase"))]
+#[derive(Clone, Copy, Pod, Zeroable)]
+#[repr(C)]
+pub struct FreezeUntilInstructionData {
+    /// The unix timestamp from which anyone may thaw the account
+    pub thaw_timestamp: UnixTimestamp,
+}
+
+/// Create a `FreezeUntil` instruction
+pub fn freeze_until(
+    token_program_id: &Pubkey,
+    account: &Pubkey,
+    mint: &Pubkey,
+    freeze_authority: &Pubkey,
+    signers: &[&Pubkey],
+    thaw_timestamp: i64,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    let mut accounts = vec![
+        AccountMeta::new(*account, false),
+        AccountMeta::new_readonly(*mint, false),
+        AccountMeta::new_readonly(*freeze_authority, signers.is_empty()),
+    ];
+    for signer_pubkey in signers.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+    Ok(encode_instruction(
+        token_program_id,
+        accounts,
+        TokenInstruction::TimedFreezeExtension,
+        TimedFreezeInstruction::FreezeUntil,
+        &FreezeUntilInstructionData {
+            thaw_timestamp: thaw_timestamp.into(),
+        },
+    ))
+}
+
+/// Create a `Thaw` instruction
+pub fn thaw(token_program_id: &Pubkey, account: &Pubkey) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    let accounts = vec![AccountMeta::new(*account, false)];
+    Ok(encode_instruction(
+        token_program_id,
+        accounts,
+        TokenInstruction::TimedFreezeExtension,
+        TimedFreezeInstruction::Thaw,
+        &(),
+    ))
+}
@@ -0,0 +1,43 @@
+#[cfg(feature = "serde-traits")]
+use serde::{Deserialize, Serialize};
+use {
+    crate::{
+        error::TokenError,
+        extension::{interest_bearing_mint::UnixTimestamp, Extension, ExtensionType},
+    },
+    bytemuck::{Pod, Zeroable},
+    solana_program::entrypoint::ProgramResult,
+};
+
+/// Instructions for the TimedFreeze extension
+pub mod instruction;
+/// Instruction processor for the TimedFreeze extension
+pub mod processor;
+
+/// Timed freeze extension data for accounts.
+///
+/// `FreezeAccount` and `ThawAccount` clear the timestamp, so it only ever
+/// applies to the freeze made alongside it.
+#[repr(C)]
+#[cfg_attr(feature = "serde-traits", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde-traits", serde(rename_all = "camelCase"))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+pub struct TimedFreeze {
+    /// Unix timestamp from which anyone may thaw the account, or 0 if only
+    /// the freeze authority may
+    pub thaw_timestamp: UnixTimestamp,
+}
+impl TimedFreeze {
+    /// Check that anyone may thaw the account at the given timestamp
+    pub fn check_thaw(&self, unix_timestamp: i64) -> ProgramResult {
+        let thaw_timestamp = i64::from(self.thaw_timestamp);
+        if thaw_timestamp == 0 || unix_timestamp < thaw_timestamp {
+            Err(TokenError::TimedFreezeNotExpired.into())
+        } else {
+            Ok(())
+        }
+    }
+}
+impl Extension for TimedFreeze {
+    const TYPE: ExtensionType = ExtensionType::TimedFreeze;
+}
@@ -0,0 +1,85 @@
+use {
+    crate::{
+        check_program_account,
+        error::TokenError,
+        extension::{
+            timed_freeze::{
+                instruction::{FreezeUntilInstructionData, TimedFreezeInstruction},
+                TimedFreeze,
+            },
+            BaseStateWithExtensionsMut, PodStateWithExtensionsMut,
+        },
+        instruction::{decode_instruction_data, decode_instruction_type},
+        pod::PodAccount,
+        processor::Processor,
+        state::AccountState,
+    },
+    solana_program::{
+        account_info::{next_account_info, AccountInfo},
+        clock::Clock,
+        entrypoint::ProgramResult,
+        msg,
+        pubkey::Pubkey,
+        sysvar::Sysvar,
+    },
+};
+
+fn process_freeze_until(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    thaw_timestamp: i64,
+) -> ProgramResult {
+    Processor::process_toggle_freeze_account(program_id, accounts, true)?;
+
+    let account_info_iter = &mut accounts.iter();
+    let token_account_info = next_account_info(account_info_iter)?;
+    let mut token_account_data = token_account_info.data.borrow_mut();
+    let mut token_account =
+        PodStateWithExtensionsMut::<PodAccount>::unpack(&mut token_account_data)?;
+
+    let extension = if let Ok(extension) = token_account.get_extension_mut::<TimedFreeze>() {
+        extension
+    } else {
+        token_account.init_extension::<TimedFreeze>(true)?
+    };
+    extension.thaw_timestamp = thaw_timestamp.into();
+    Ok(())
+}
+
+fn process_thaw(accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let token_account_info = next_account_info(account_info_iter)?;
+
+    check_program_account(token_account_info.owner)?;
+    let mut token_account_data = token_account_info.data.borrow_mut();
+    let mut token_account =
+        PodStateWithExtensionsMut::<PodAccount>::unpack(&mut token_account_data)?;
+    if !token_account.base.is_frozen() {
+        return Err(TokenError::InvalidState.into());
+    }
+
+    let extension = token_account.get_extension_mut::<TimedFreeze>()?;
+    extension.check_thaw(Clock::get()?.unix_timestamp)?;
+    extension.thaw_timestamp = 0.into();
+    token_account.base.state = AccountState::Initialized.into();
+    Ok(())
+}
+
+pub(crate) fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    input: &[u8],
+) -> ProgramResult {
+    check_program_account(program_id)?;
+    match decode_instruction_type(input)? {
+        TimedFreezeInstruction::FreezeUntil => {
+            msg!("TimedFreezeInstruction::FreezeUntil");
+            let FreezeUntilInstructionData { thaw_timestamp } = decode_instruction_data(input)?;
+            process_freeze_until(program_id, accounts, i64::from(*thaw_timestamp))
+        }
+        TimedFreezeInstruction::Thaw => {
+            msg!("TimedFreezeInstruction::Thaw");
+            process_thaw(accounts)
+        }
+    }
+}
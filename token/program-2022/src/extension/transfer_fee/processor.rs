@@ -3,6 +3,9 @@ use {
         check_program_account,
         error::TokenError,
         extension::{
+            pausable::check_not_paused,
+            royalty::RoyaltyConfig,
+            snapshot::{write_checkpoint, SnapshotConfig},
             transfer_fee::{
                 instruction::TransferFeeInstruction, TransferFee, TransferFeeAmount,
                 TransferFeeConfig, MAX_FEE_BASIS_POINTS,
@@ -72,6 +75,7 @@ fn process_set_transfer_fee(
 
     let mut mint_data = mint_account_info.data.borrow_mut();
     let mut mint = PodStateWithExtensionsMut::<PodMint>::unpack(&mut mint_data)?;
+    let royalty_config = mint.get_extension::<RoyaltyConfig>().ok().copied();
     let extension = mint.get_extension_mut::<TransferFeeConfig>()?;
 
     let transfer_fee_config_authority =
@@ -107,6 +111,9 @@ fn process_set_transfer_fee(
         maximum_fee: maximum_fee.into(),
     };
     extension.newer_transfer_fee = transfer_fee;
+    if let Some(royalty_config) = royalty_config {
+        royalty_config.check_transfer_fee(extension)?;
+    }
 
     Ok(())
 }
@@ -126,6 +133,8 @@ fn process_withdraw_withheld_tokens_from_mint(
 
     let mut mint_data = mint_account_info.data.borrow_mut();
     let mut mint = PodStateWithExtensionsMut::<PodMint>::unpack(&mut mint_data)?;
+    check_not_paused(&mint)?;
+    let maybe_snapshot = mint.get_extension::<SnapshotConfig>().ok().copied();
     let extension = mint.get_extension_mut::<TransferFeeConfig>()?;
 
     let withdraw_withheld_authority = Option::<Pubkey>::from(extension.withdraw_withheld_authority)
@@ -139,7 +148,7 @@ fn process_withdraw_withheld_tokens_from_mint(
     )?;
 
     let mut destination_account_data = destination_account_info.data.borrow_mut();
-    let destination_account =
+    let mut destination_account =
         PodStateWithExtensionsMut::<PodAccount>::unpack(&mut destination_account_data)?;
     if destination_account.base.mint != *mint_account_info.key {
         return Err(TokenError::MintMismatch.into());
@@ -147,6 +156,9 @@ fn process_withdraw_withheld_tokens_from_mint(
     if destination_account.base.is_frozen() {
         return Err(TokenError::AccountFrozen.into());
     }
+    if let Some(ref snapshot) = maybe_snapshot {
+        write_checkpoint(snapshot, &mut destination_account)?;
+    }
     let withheld_amount = u64::from(extension.withheld_amount);
     extension.withheld_amount = 0.into();
     destination_account.base.amount = u64::from(destination_account.base.amount)
@@ -184,6 +196,7 @@ fn process_harvest_withheld_tokens_to_mint(accounts: &[AccountInfo]) -> ProgramR
 
     let mut mint_data = mint_account_info.data.borrow_mut();
     let mut mint = PodStateWithExtensionsMut::<PodMint>::unpack(&mut mint_data)?;
+    check_not_paused(&mint)?;
     let mint_extension = mint.get_extension_mut::<TransferFeeConfig>()?;
 
     for token_account_info in token_account_infos {
@@ -223,6 +236,7 @@ fn process_withdraw_withheld_tokens_from_accounts(
 
     let mint_data = mint_account_info.data.borrow();
     let mint = PodStateWithExtensions::<PodMint>::unpack(&mint_data)?;
+    check_not_paused(&mint)?;
     let extension = mint.get_extension::<TransferFeeConfig>()?;
 
     let withdraw_withheld_authority = Option::<Pubkey>::from(extension.withdraw_withheld_authority)
@@ -244,6 +258,9 @@ fn process_withdraw_withheld_tokens_from_accounts(
     if destination_account.base.is_frozen() {
         return Err(TokenError::AccountFrozen.into());
     }
+    if let Ok(snapshot) = mint.get_extension::<SnapshotConfig>() {
+        write_checkpoint(snapshot, &mut destination_account)?;
+    }
     for account_info in &account_infos[num_signers..] {
         // self-harvest, can't double-borrow the underlying data
         if account_info.key == destination_account_info.key {
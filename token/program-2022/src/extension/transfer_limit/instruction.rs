@@ -0,0 +1,229 @@
+#[cfg(feature = "serde-traits")]
+use serde::{Deserialize, Serialize};
+use {
+    crate::{
+        check_program_account,
+        instruction::{encode_instruction, TokenInstruction},
+    },
+    bytemuck::{Pod, Zeroable},
+    num_enum::{IntoPrimitive, TryFromPrimitive},
+    solana_program::{
+        instruction::{AccountMeta, Instruction},
+        program_error::ProgramError,
+        pubkey::Pubkey,
+    },
+    spl_pod::{optional_keys::OptionalNonZeroPubkey, primitives::PodU64},
+    std::convert::TryInto,
+};
+
+/// Transfer limit extension instructions
+#[cfg_attr(feature = "serde-traits", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde-traits", serde(rename_all = "camelCase"))]
+#[derive(Clone, Copy, Debug, PartialEq, IntoPrimitive, TryFromPrimitive)]
+#[repr(u8)]
+pub enum TransferLimitInstruction {
+    /// Initialize a new mint with transfer limits.
+    ///
+    /// Fails if the mint has already been initialized, so must be called before
+    /// `InitializeMint`.
+    ///
+    /// The mint must have exactly enough space allocated for the base mint (82
+    /// bytes), plus 83 bytes of padding, 1 byte reserved for the account type,
+    /// then space required for this extension, plus any others.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` The mint to initialize.
+    ///
+    /// Data expected by this instruction:
+    ///   `crate::extension::transfer_limit::instruction::InitializeInstructionData`
+    Initialize,
+    /// Update the transfer limits. Only supported for mints that include the
+    /// `TransferLimitConfig` extension.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single authority
+    ///   0. `[writable]` The mint.
+    ///   1. `[signer]` The transfer limit authority.
+    ///
+    ///   * Multisignature authority
+    ///   0. `[writable]` The mint.
+    ///   1. `[]` The mint's multisignature transfer limit authority.
+    ///   2. ..2+M `[signer]` M signer accounts.
+    ///
+    /// Data expected by this instruction:
+    ///   `crate::extension::transfer_limit::instruction::UpdateLimitsInstructionData`
+    UpdateLimits,
+    /// Add a token account to the mint's override list, exempting it from the
+    /// transfer limits.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single authority
+    ///   0. `[writable]` The token account.
+    ///   1. `[]` The token mint.
+    ///   2. `[signer]` The mint's transfer limit authority.
+    ///
+    ///   * Multisignature authority
+    ///   0. `[writable]` The token account.
+    ///   1. `[]` The token mint.
+    ///   2. `[]` The mint's multisignature transfer limit authority.
+    ///   3. ..3+M `[signer]` M signer accounts.
+    AddExemption,
+    /// Remove a token account from the mint's override list.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single authority
+    ///   0. `[writable]` The token account.
+    ///   1. `[]` The token mint.
+    ///   2. `[signer]` The mint's transfer limit authority.
+    ///
+    ///   * Multisignature authority
+    ///   0. `[writable]` The token account.
+    ///   1. `[]` The token mint.
+    ///   2. `[]` The mint's multisignature transfer limit authority.
+    ///   3. ..3+M `[signer]` M signer accounts.
+    RemoveExemption,
+}
+
+/// Data expected by `TransferLimitInstruction::Initialize`
+#[cfg_attr(feature = "serde-traits", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde-traits", serde(rename_all = "camelCase"))]
+#[derive(Clone, Copy, Pod, Zeroable)]
+#[repr(C)]
+pub struct InitializeInstructionData {
+    /// The public key for the account that can update the limits
+    pub authority: OptionalNonZeroPubkey,
+    /// The maximum amount moved by a single transfer
+    pub max_per_transfer: PodU64,
+    /// The maximum amount sent by an account within a day
+    pub daily_cap: PodU64,
+}
+
+/// Data expected by `TransferLimitInstruction::UpdateLimits`
+#[cfg_attr(feature = "serde-traits", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde-traits", serde(rename_all = "camelCase"))]
+#[derive(Clone, Copy, Pod, Zeroable)]
+#[repr(C)]
+pub struct UpdateLimitsInstructionData {
+    /// The new maximum amount moved by a single transfer
+    pub max_per_transfer: PodU64,
+    /// The new maximum amount sent by an account within a day
+    pub daily_cap: PodU64,
+}
+
+/// Create an `Initialize` instruction
+pub fn initialize(
+    token_program_id: &Pubkey,
+    mint: &Pubkey,
+    authority: Option<Pubkey>,
+    max_per_transfer: u64,
+    daily_cap: u64,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    let accounts = vec![AccountMeta::new(*mint, false)];
+    Ok(encode_instruction(
+        token_program_id,
+        accounts,
+        TokenInstruction::TransferLimitExtension,
+        TransferLimitInstruction::Initialize,
+        &InitializeInstructionData {
+            authority: authority.try_into()?,
+            max_per_transfer: max_per_transfer.into(),
+            daily_cap: daily_cap.into(),
+        },
+    ))
+}
+
+/// Create an `UpdateLimits` instruction
+pub fn update_limits(
+    token_program_id: &Pubkey,
+    mint: &Pubkey,
+    authority: &Pubkey,
+    signers: &[&Pubkey],
+    max_per_transfer: u64,
+    daily_cap: u64,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    let mut accounts = vec![
+        AccountMeta::new(*mint, false),
+        AccountMeta::new_readonly(*authority, signers.is_empty()),
+    ];
+    for signer_pubkey in signers.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+    Ok(encode_instruction(
+        token_program_id,
+        accounts,
+        TokenInstruction::TransferLimitExtension,
+        TransferLimitInstruction::UpdateLimits,
+        &UpdateLimitsInstructionData {
+            max_per_transfer: max_per_transfer.into(),
+            daily_cap: daily_cap.into(),
+        },
+    ))
+}
+
+fn encode_exemption_instruction(
+    token_program_id: &Pubkey,
+    account: &Pubkey,
+    mint: &Pubkey,
+    authority: &Pubkey,
+    signers: &[&Pubkey],
+    instruction_type: TransferLimitInstruction,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    let mut accounts = vec![
+        AccountMeta::new(*account, false),
+        AccountMeta::new_readonly(*mint, false),
+        AccountMeta::new_readonly(*authority, signers.is_empty()),
+    ];
+    for signer_pubkey in signers.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+    Ok(encode_instruction(
+        token_program_id,
+        accounts,
+        TokenInstruction::TransferLimitExtension,
+        instruction_type,
+        &(),
+    ))
+}
+
+/// Create an `AddExemption` instruction
+pub fn add_exemption(
+    token_program_id: &Pubkey,
+    account: &Pubkey,
+    mint: &Pubkey,
+    authority: &Pubkey,
+    signers: &[&Pubkey],
+) -> Result<Instruction, ProgramError> {
+    encode_exemption_instruction(
+        token_program_id,
+        account,
+        mint,
+        authority,
+        signers,
+        TransferLimitInstruction::AddExemption,
+    )
+}
+
+/// Create a `RemoveExemption` instruction
+pub fn remove_exemption(
+    token_program_id: &Pubkey,
+    account: &Pubkey,
+    mint: &Pubkey,
+    authority: &Pubkey,
+    signers: &[&Pubkey],
+) -> Result<Instruction, ProgramError> {
+    encode_exemption_instruction(
+        token_program_id,
+        account,
+        mint,
+        authority,
+        signers,
+        TransferLimitInstruction::RemoveExemption,
+    )
+}
@@ -0,0 +1,154 @@
+#[cfg(feature = "serde-traits")]
+use serde::{Deserialize, Serialize};
+use {
+    crate::{
+        error::TokenError,
+        extension::{interest_bearing_mint::UnixTimestamp, Extension, ExtensionType},
+    },
+    bytemuck::{Pod, Zeroable},
+    solana_program::entrypoint::ProgramResult,
+    spl_pod::{
+        optional_keys::OptionalNonZeroPubkey,
+        primitives::{PodBool, PodU64},
+    },
+};
+
+/// Instructions for the TransferLimit extension
+pub mod instruction;
+/// Instruction processor for the TransferLimit extension
+pub mod processor;
+
+/// Length of the window over which the daily cap applies, in seconds
+pub const SECONDS_PER_DAY: i64 = 60 * 60 * 24;
+
+/// Transfer limit extension data for mints.
+///
+/// Set a limit to `u64::MAX` to disable it.
+#[repr(C)]
+#[cfg_attr(feature = "serde-traits", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde-traits", serde(rename_all = "camelCase"))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+pub struct TransferLimitConfig {
+    /// Authority that can update the limits and exempt accounts from them
+    pub authority: OptionalNonZeroPubkey,
+    /// Maximum amount of tokens moved by a single transfer
+    pub max_per_transfer: PodU64,
+    /// Maximum amount of tokens sent by an account within a day
+    pub daily_cap: PodU64,
+}
+
+/// Outgoing transfer usage for accounts belonging to a mint with transfer
+/// limits.
+///
+/// The daily window opens with the first transfer made after the previous
+/// window has run for `SECONDS_PER_DAY`.
+#[repr(C)]
+#[cfg_attr(feature = "serde-traits", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde-traits", serde(rename_all = "camelCase"))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+pub struct TransferLimitAccount {
+    /// Timestamp at which the current window opened
+    pub window_start: UnixTimestamp,
+    /// Amount of tokens sent during the current window
+    pub window_amount: PodU64,
+    /// Account is on the mint's override list, and not subject to the limits
+    pub exempt: PodBool,
+}
+impl TransferLimitAccount {
+    /// Record an outgoing transfer against the account's daily window, failing
+    /// if it would exceed either of the mint's limits
+    pub fn record_transfer(
+        &mut self,
+        config: &TransferLimitConfig,
+        amount: u64,
+        unix_timestamp: i64,
+    ) -> ProgramResult {
+        if bool::from(self.exempt) {
+            return Ok(());
+        }
+        if amount > u64::from(config.max_per_transfer) {
+            return Err(TokenError::TransferLimitExceeded.into());
+        }
+        let window_expired =
+            unix_timestamp.saturating_sub(self.window_start.into()) >= SECONDS_PER_DAY;
+        let (window_start, window_amount) = if window_expired {
+            (unix_timestamp, 0)
+        } else {
+            (self.window_start.into(), u64::from(self.window_amount))
+        };
+        let window_amount = window_amount
+            .checked_add(amount)
+            .ok_or(TokenError::Overflow)?;
+        if window_amount > u64::from(config.daily_cap) {
+            return Err(TokenError::DailyTransferLimitExceeded.into());
+        }
+        self.window_start = window_start.into();
+        self.window_amount = window_amount.into();
+        Ok(())
+    }
+}
+
+impl Extension for TransferLimitConfig {
+    const TYPE: ExtensionType = ExtensionType::TransferLimitConfig;
+}
+
+impl Extension for TransferLimitAccount {
+    const TYPE: ExtensionType = ExtensionType::TransferLimitAccount;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> TransferLimitConfig {
+        TransferLimitConfig {
+            authority: OptionalNonZeroPubkey::default(),
+            max_per_transfer: 100.into(),
+            daily_cap: 250.into(),
+        }
+    }
+
+    #[test]
+    fn per_transfer_limit() {
+        let mut account = TransferLimitAccount::default();
+        assert_eq!(
+            account.record_transfer(&config(), 101, 0),
+            Err(TokenError::TransferLimitExceeded.into())
+        );
+        assert_eq!(account, TransferLimitAccount::default());
+        account.record_transfer(&config(), 100, 0).unwrap();
+        assert_eq!(u64::from(account.window_amount), 100);
+    }
+
+    #[test]
+    fn daily_cap() {
+        let mut account = TransferLimitAccount::default();
+        let start = 1_000_000;
+        account.record_transfer(&config(), 100, start).unwrap();
+        account.record_transfer(&config(), 100, start + 1).unwrap();
+        account.record_transfer(&config(), 50, start + 2).unwrap();
+        assert_eq!(
+            account.record_transfer(&config(), 1, start + SECONDS_PER_DAY - 1),
+            Err(TokenError::DailyTransferLimitExceeded.into())
+        );
+        assert_eq!(i64::from(account.window_start), start);
+        assert_eq!(u64::from(account.window_amount), 250);
+
+        // a new window opens a day after the previous one
+        account
+            .record_transfer(&config(), 100, start + SECONDS_PER_DAY)
+            .unwrap();
+        assert_eq!(i64::from(account.window_start), start + SECONDS_PER_DAY);
+        assert_eq!(u64::from(account.window_amount), 100);
+    }
+
+    #[test]
+    fn exempt() {
+        let mut account = TransferLimitAccount {
+            exempt: true.into(),
+            ..TransferLimitAccount::default()
+        };
+        account.record_transfer(&config(), u64::MAX, 0).unwrap();
+        assert_eq!(u64::from(account.window_amount), 0);
+    }
+}
@@ -0,0 +1,149 @@
+use {
+    crate::{
+        check_program_account,
+        error::TokenError,
+        extension::{
+            transfer_limit::{
+                instruction::{
+                    InitializeInstructionData, TransferLimitInstruction,
+                    UpdateLimitsInstructionData,
+                },
+                TransferLimitAccount, TransferLimitConfig,
+            },
+            BaseStateWithExtensions, BaseStateWithExtensionsMut, PodStateWithExtensions,
+            PodStateWithExtensionsMut,
+        },
+        instruction::{decode_instruction_data, decode_instruction_type},
+        pod::{PodAccount, PodMint},
+        processor::Processor,
+    },
+    solana_program::{
+        account_info::{next_account_info, AccountInfo},
+        entrypoint::ProgramResult,
+        msg,
+        pubkey::Pubkey,
+    },
+    spl_pod::{optional_keys::OptionalNonZeroPubkey, primitives::PodU64},
+};
+
+fn process_initialize(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    authority: &OptionalNonZeroPubkey,
+    max_per_transfer: &PodU64,
+    daily_cap: &PodU64,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let mint_account_info = next_account_info(account_info_iter)?;
+    let mut mint_data = mint_account_info.data.borrow_mut();
+    let mut mint = PodStateWithExtensionsMut::<PodMint>::unpack_uninitialized(&mut mint_data)?;
+
+    let extension = mint.init_extension::<TransferLimitConfig>(true)?;
+    extension.authority = *authority;
+    extension.max_per_transfer = *max_per_transfer;
+    extension.daily_cap = *daily_cap;
+    Ok(())
+}
+
+fn process_update_limits(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    max_per_transfer: &PodU64,
+    daily_cap: &PodU64,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let mint_account_info = next_account_info(account_info_iter)?;
+    let owner_info = next_account_info(account_info_iter)?;
+    let owner_info_data_len = owner_info.data_len();
+
+    let mut mint_data = mint_account_info.data.borrow_mut();
+    let mut mint = PodStateWithExtensionsMut::<PodMint>::unpack(&mut mint_data)?;
+    let extension = mint.get_extension_mut::<TransferLimitConfig>()?;
+    let authority =
+        Option::<Pubkey>::from(extension.authority).ok_or(TokenError::NoAuthorityExists)?;
+
+    Processor::validate_owner(
+        program_id,
+        &authority,
+        owner_info,
+        owner_info_data_len,
+        account_info_iter.as_slice(),
+    )?;
+
+    extension.max_per_transfer = *max_per_transfer;
+    extension.daily_cap = *daily_cap;
+    Ok(())
+}
+
+fn process_set_exemption(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    exempt: bool,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let token_account_info = next_account_info(account_info_iter)?;
+    let mint_info = next_account_info(account_info_iter)?;
+    let authority_info = next_account_info(account_info_iter)?;
+    let authority_info_data_len = authority_info.data_len();
+
+    let mut token_account_data = token_account_info.data.borrow_mut();
+    let mut token_account =
+        PodStateWithExtensionsMut::<PodAccount>::unpack(&mut token_account_data)?;
+    if token_account.base.mint != *mint_info.key {
+        return Err(TokenError::MintMismatch.into());
+    }
+
+    check_program_account(mint_info.owner)?;
+    let mint_data = mint_info.data.borrow();
+    let mint = PodStateWithExtensions::<PodMint>::unpack(&mint_data)?;
+    let extension = mint.get_extension::<TransferLimitConfig>()?;
+    let authority =
+        Option::<Pubkey>::from(extension.authority).ok_or(TokenError::NoAuthorityExists)?;
+
+    Processor::validate_owner(
+        program_id,
+        &authority,
+        authority_info,
+        authority_info_data_len,
+        account_info_iter.as_slice(),
+    )?;
+
+    let extension = token_account.get_extension_mut::<TransferLimitAccount>()?;
+    extension.exempt = exempt.into();
+    Ok(())
+}
+
+pub(crate) fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    input: &[u8],
+) -> ProgramResult {
+    check_program_account(program_id)?;
+    match decode_instruction_type(input)? {
+        TransferLimitInstruction::Initialize => {
+            msg!("TransferLimitInstruction::Initialize");
+            let InitializeInstructionData {
+                authority,
+                max_per_transfer,
+                daily_cap,
+            } = decode_instruction_data(input)?;
+            process_initialize(program_id, accounts, authority, max_per_transfer, daily_cap)
+        }
+        TransferLimitInstruction::UpdateLimits => {
+            msg!("TransferLimitInstruction::UpdateLimits");
+            let UpdateLimitsInstructionData {
+                max_per_transfer,
+                daily_cap,
+            } = decode_instruction_data(input)?;
+            process_update_limits(program_id, accounts, max_per_transfer, daily_cap)
+        }
+        TransferLimitInstruction::AddExemption => {
+            msg!("TransferLimitInstruction::AddExemption");
+            process_set_exemption(program_id, accounts, true /* exempt */)
+        }
+        TransferLimitInstruction::RemoveExemption => {
+            msg!("TransferLimitInstruction::RemoveExemption");
+            process_set_exemption(program_id, accounts, false /* exempt */)
+        }
+    }
+}
@@ -0,0 +1,232 @@
+#[cfg(feature = "serde-traits")]
+use serde::{Deserialize, Serialize};
+use {
+    crate::{
+        check_program_account,
+        instruction::{encode_instruction, TokenInstruction},
+    },
+    bytemuck::{Pod, Zeroable},
+    num_enum::{IntoPrimitive, TryFromPrimitive},
+    solana_program::{
+        instruction::{AccountMeta, Instruction},
+        program_error::ProgramError,
+        pubkey::Pubkey,
+    },
+    spl_pod::{optional_keys::OptionalNonZeroPubkey, primitives::PodBool},
+    std::convert::TryInto,
+};
+
+/// Transfer restriction extension instructions
+#[cfg_attr(feature = "serde-traits", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde-traits", serde(rename_all = "camelCase"))]
+#[derive(Clone, Copy, Debug, PartialEq, IntoPrimitive, TryFromPrimitive)]
+#[repr(u8)]
+pub enum TransferRestrictionInstruction {
+    /// Initialize a new mint with transfer restrictions.
+    ///
+    /// Fails if the mint has already been initialized, so must be called before
+    /// `InitializeMint`.
+    ///
+    /// The mint must have exactly enough space allocated for the base mint (82
+    /// bytes), plus 83 bytes of padding, 1 byte reserved for the account type,
+    /// then space required for this extension, plus any others.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` The mint to initialize.
+    ///
+    /// Data expected by this instruction:
+    ///   `crate::extension::transfer_restriction::instruction::InitializeInstructionData`
+    Initialize,
+    /// Add a token account to the mint's allowlist.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single authority
+    ///   0. `[writable]` The token account.
+    ///   1. `[]` The token mint.
+    ///   2. `[signer]` The mint's transfer restriction authority.
+    ///
+    ///   * Multisignature authority
+    ///   0. `[writable]` The token account.
+    ///   1. `[]` The token mint.
+    ///   2. `[]` The mint's multisignature transfer restriction authority.
+    ///   3. ..3+M `[signer]` M signer accounts.
+    AddToAllowlist,
+    /// Remove a token account from the mint's allowlist.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single authority
+    ///   0. `[writable]` The token account.
+    ///   1. `[]` The token mint.
+    ///   2. `[signer]` The mint's transfer restriction authority.
+    ///
+    ///   * Multisignature authority
+    ///   0. `[writable]` The token account.
+    ///   1. `[]` The token mint.
+    ///   2. `[]` The mint's multisignature transfer restriction authority.
+    ///   3. ..3+M `[signer]` M signer accounts.
+    RemoveFromAllowlist,
+    /// Add a token account to the mint's denylist.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single authority
+    ///   0. `[writable]` The token account.
+    ///   1. `[]` The token mint.
+    ///   2. `[signer]` The mint's transfer restriction authority.
+    ///
+    ///   * Multisignature authority
+    ///   0. `[writable]` The token account.
+    ///   1. `[]` The token mint.
+    ///   2. `[]` The mint's multisignature transfer restriction authority.
+    ///   3. ..3+M `[signer]` M signer accounts.
+    AddToDenylist,
+    /// Remove a token account from the mint's denylist.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single authority
+    ///   0. `[writable]` The token account.
+    ///   1. `[]` The token mint.
+    ///   2. `[signer]` The mint's transfer restriction authority.
+    ///
+    ///   * Multisignature authority
+    ///   0. `[writable]` The token account.
+    ///   1. `[]` The token mint.
+    ///   2. `[]` The mint's multisignature transfer restriction authority.
+    ///   3. ..3+M `[signer]` M signer accounts.
+    RemoveFromDenylist,
+}
+
+/// Data expected by `TransferRestrictionInstruction::Initialize`
+#[cfg_attr(feature = "serde-traits", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde-traits", serde(rename_all = "camelCase"))]
+#[derive(Clone, Copy, Pod, Zeroable)]
+#[repr(C)]
+pub struct InitializeInstructionData {
+    /// The public key for the account that can update the lists
+    pub authority: OptionalNonZeroPubkey,
+    /// If true, only allowlisted accounts may send or receive tokens
+    pub allowlist_required: PodBool,
+}
+
+/// Create an `Initialize` instruction
+pub fn initialize(
+    token_program_id: &Pubkey,
+    mint: &Pubkey,
+    authority: Option<Pubkey>,
+    allowlist_required: bool,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    let accounts = vec![AccountMeta::new(*mint, false)];
+    Ok(encode_instruction(
+        token_program_id,
+        accounts,
+        TokenInstruction::TransferRestrictionExtension,
+        TransferRestrictionInstruction::Initialize,
+        &InitializeInstructionData {
+            authority: authority.try_into()?,
+            allowlist_required: allowlist_required.into(),
+        },
+    ))
+}
+
+fn encode_list_instruction(
+    token_program_id: &Pubkey,
+    account: &Pubkey,
+    mint: &Pubkey,
+    authority: &Pubkey,
+    signers: &[&Pubkey],
+    instruction_type: TransferRestrictionInstruction,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    let mut accounts = vec![
+        AccountMeta::new(*account, false),
+        AccountMeta::new_readonly(*mint, false),
+        AccountMeta::new_readonly(*authority, signers.is_empty()),
+    ];
+    for signer_pubkey in signers.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+    Ok(encode_instruction(
+        token_program_id,
+        accounts,
+        TokenInstruction::TransferRestrictionExtension,
+        instruction_type,
+        &(),
+    ))
+}
+
+/// Create an `AddToAllowlist` instruction
+pub fn add_to_allowlist(
+    token_program_id: &Pubkey,
+    account: &Pubkey,
+    mint: &Pubkey,
+    authority: &Pubkey,
+    signers: &[&Pubkey],
+) -> Result<Instruction, ProgramError> {
+    encode_list_instruction(
+        token_program_id,
+        account,
+        mint,
+        authority,
+        signers,
+        TransferRestrictionInstruction::AddToAllowlist,
+    )
+}
+
+/// Create a `RemoveFromAllowlist` instruction
+pub fn remove_from_allowlist(
+    token_program_id: &Pubkey,
+    account: &Pubkey,
+    mint: &Pubkey,
+    authority: &Pubkey,
+    signers: &[&Pubkey],
+) -> Result<Instruction, ProgramError> {
+    encode_list_instruction(
+        token_program_id,
+        account,
+        mint,
+        authority,
+        signers,
+        TransferRestrictionInstruction::RemoveFromAllowlist,
+    )
+}
+
+/// Create an `AddToDenylist` instruction
+pub fn add_to_denylist(
+    token_program_id: &Pubkey,
+    account: &Pubkey,
+    mint: &Pubkey,
+    authority: &Pubkey,
+    signers: &[&Pubkey],
+) -> Result<Instruction, ProgramError> {
+    encode_list_instruction(
+        token_program_id,
+        account,
+        mint,
+        authority,
+        signers,
+        TransferRestrictionInstruction::AddToDenylist,
+    )
+}
+
+/// Create a `RemoveFromDenylist` instruction
+pub fn remove_from_denylist(
+    token_program_id: &Pubkey,
+    account: &Pubkey,
+    mint: &Pubkey,
+    authority: &Pubkey,
+    signers: &[&Pubkey],
+) -> Result<Instruction, ProgramError> {
+    encode_list_instruction(
+        token_program_id,
+        account,
+        mint,
+        authority,
+        signers,
+        TransferRestrictionInstruction::RemoveFromDenylist,
+    )
+}
@@ -0,0 +1,75 @@
+#[cfg(feature = "serde-traits")]
+use serde::{Deserialize, Serialize};
+use {
+    crate::{
+        error::TokenError,
+        extension::{BaseState, BaseStateWithExtensions, Extension, ExtensionType},
+    },
+    bytemuck::{Pod, Zeroable},
+    solana_program::entrypoint::ProgramResult,
+    spl_pod::{optional_keys::OptionalNonZeroPubkey, primitives::PodBool},
+};
+
+/// Instructions for the TransferRestriction extension
+pub mod instruction;
+/// Instruction processor for the TransferRestriction extension
+pub mod processor;
+
+/// Transfer restriction extension data for mints.
+#[repr(C)]
+#[cfg_attr(feature = "serde-traits", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde-traits", serde(rename_all = "camelCase"))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+pub struct TransferRestrictionConfig {
+    /// Authority that can add accounts to and remove accounts from the lists
+    pub authority: OptionalNonZeroPubkey,
+    /// If true, only allowlisted accounts may send or receive tokens
+    pub allowlist_required: PodBool,
+}
+
+/// Allowlist and denylist flags for accounts belonging to a mint with
+/// transfer restrictions
+#[repr(C)]
+#[cfg_attr(feature = "serde-traits", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde-traits", serde(rename_all = "camelCase"))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+pub struct TransferRestriction {
+    /// Account is on the mint's allowlist
+    pub allowlisted: PodBool,
+    /// Account is on the mint's denylist
+    pub denylisted: PodBool,
+}
+impl TransferRestriction {
+    /// Check that the account may take part in a transfer
+    pub fn check(&self, allowlist_required: bool) -> ProgramResult {
+        if bool::from(self.denylisted) {
+            Err(TokenError::AccountDenylisted.into())
+        } else if allowlist_required && !bool::from(self.allowlisted) {
+            Err(TokenError::AccountNotAllowlisted.into())
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl Extension for TransferRestrictionConfig {
+    const TYPE: ExtensionType = ExtensionType::TransferRestrictionConfig;
+}
+
+impl Extension for TransferRestriction {
+    const TYPE: ExtensionType = ExtensionType::TransferRestriction;
+}
+
+/// Check that the given account may take part in a transfer of tokens from a
+/// mint with the given transfer restriction config
+pub fn check_transfer_restriction<BSE: BaseStateWithExtensions<S>, S: BaseState>(
+    config: &TransferRestrictionConfig,
+    account_state: &BSE,
+) -> ProgramResult {
+    let allowlist_required = bool::from(config.allowlist_required);
+    match account_state.get_extension::<TransferRestriction>() {
+        Ok(extension) => extension.check(allowlist_required),
+        Err(_) if allowlist_required => Err(TokenError::AccountNotAllowlisted.into()),
+        Err(_) => Ok(()),
+    }
+}
@@ -0,0 +1,122 @@
+use {
+    crate::{
+        check_program_account,
+        error::TokenError,
+        extension::{
+            transfer_restriction::{
+                instruction::{InitializeInstructionData, TransferRestrictionInstruction},
+                TransferRestriction, TransferRestrictionConfig,
+            },
+            BaseStateWithExtensions, BaseStateWithExtensionsMut, PodStateWithExtensions,
+            PodStateWithExtensionsMut,
+        },
+        instruction::{decode_instruction_data, decode_instruction_type},
+        pod::{PodAccount, PodMint},
+        processor::Processor,
+    },
+    solana_program::{
+        account_info::{next_account_info, AccountInfo},
+        entrypoint::ProgramResult,
+        msg,
+        pubkey::Pubkey,
+    },
+    spl_pod::{optional_keys::OptionalNonZeroPubkey, primitives::PodBool},
+};
+
+fn process_initialize(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    authority: &OptionalNonZeroPubkey,
+    allowlist_required: &PodBool,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let mint_account_info = next_account_info(account_info_iter)?;
+    let mut mint_data = mint_account_info.data.borrow_mut();
+    let mut mint = PodStateWithExtensionsMut::<PodMint>::unpack_uninitialized(&mut mint_data)?;
+
+    let extension = mint.init_extension::<TransferRestrictionConfig>(true)?;
+    extension.authority = *authority;
+    extension.allowlist_required = *allowlist_required;
+    Ok(())
+}
+
+/// Update the list flags of a token account, after checking the mint's
+/// transfer restriction authority
+fn process_update_lists<F: FnOnce(&mut TransferRestriction)>(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    update: F,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let token_account_info = next_account_info(account_info_iter)?;
+    let mint_info = next_account_info(account_info_iter)?;
+    let authority_info = next_account_info(account_info_iter)?;
+    let authority_info_data_len = authority_info.data_len();
+
+    let mut token_account_data = token_account_info.data.borrow_mut();
+    let mut token_account =
+        PodStateWithExtensionsMut::<PodAccount>::unpack(&mut token_account_data)?;
+    if token_account.base.mint != *mint_info.key {
+        return Err(TokenError::MintMismatch.into());
+    }
+
+    check_program_account(mint_info.owner)?;
+    let mint_data = mint_info.data.borrow();
+    let mint = PodStateWithExtensions::<PodMint>::unpack(&mint_data)?;
+    let extension = mint.get_extension::<TransferRestrictionConfig>()?;
+    let authority =
+        Option::<Pubkey>::from(extension.authority).ok_or(TokenError::NoAuthorityExists)?;
+
+    Processor::validate_owner(
+        program_id,
+        &authority,
+        authority_info,
+        authority_info_data_len,
+        account_info_iter.as_slice(),
+    )?;
+
+    update(token_account.get_extension_mut::<TransferRestriction>()?);
+    Ok(())
+}
+
+pub(crate) fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    input: &[u8],
+) -> ProgramResult {
+    check_program_account(program_id)?;
+    match decode_instruction_type(input)? {
+        TransferRestrictionInstruction::Initialize => {
+            msg!("TransferRestrictionInstruction::Initialize");
+            let InitializeInstructionData {
+                authority,
+                allowlist_required,
+            } = decode_instruction_data(input)?;
+            process_initialize(program_id, accounts, authority, allowlist_required)
+        }
+        TransferRestrictionInstruction::AddToAllowlist => {
+            msg!("TransferRestrictionInstruction::AddToAllowlist");
+            process_update_lists(program_id, accounts, |extension| {
+                extension.allowlisted = true.into()
+            })
+        }
+        TransferRestrictionInstruction::RemoveFromAllowlist => {
+            msg!("TransferRestrictionInstruction::RemoveFromAllowlist");
+            process_update_lists(program_id, accounts, |extension| {
+                extension.allowlisted = false.into()
+            })
+        }
+        TransferRestrictionInstruction::AddToDenylist => {
+            msg!("TransferRestrictionInstruction::AddToDenylist");
+            process_update_lists(program_id, accounts, |extension| {
+                extension.denylisted = true.into()
+            })
+        }
+        TransferRestrictionInstruction::RemoveFromDenylist => {
+            msg!("TransferRestrictionInstruction::RemoveFromDenylist");
+            process_update_lists(program_id, accounts, |extension| {
+                extension.denylisted = false.into()
+            })
+        }
+    }
+}
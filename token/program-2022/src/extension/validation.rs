@@ -0,0 +1,251 @@
+use {
+    crate::{
+        error::TokenError,
+        extension::{
+            max_supply::MaxSupplyConfig, royalty::RoyaltyConfig, BaseStateWithExtensions,
+            ExtensionType, PodStateWithExtensions,
+        },
+        pod::{PodAccount, PodMint},
+    },
+    solana_program::{entrypoint::ProgramResult, pubkey::Pubkey},
+};
+
+/// Get the mint extension that an account extension belongs to, if any
+fn get_mint_extension_for_account_extension(
+    account_extension_type: &ExtensionType,
+) -> Option<ExtensionType> {
+    match account_extension_type {
+        ExtensionType::TransferFeeAmount => Some(ExtensionType::TransferFeeConfig),
+        ExtensionType::ConfidentialTransferFeeAmount => {
+            Some(ExtensionType::ConfidentialTransferFeeConfig)
+        }
+        ExtensionType::ConfidentialTransferAccount => Some(ExtensionType::ConfidentialTransferMint),
+        ExtensionType::NonTransferableAccount => Some(ExtensionType::NonTransferable),
+        ExtensionType::TransferHookAccount => Some(ExtensionType::TransferHook),
+        ExtensionType::VestingAccount => Some(ExtensionType::VestingConfig),
+        ExtensionType::TransferRestriction => Some(ExtensionType::TransferRestrictionConfig),
+        ExtensionType::TransferLimitAccount => Some(ExtensionType::TransferLimitConfig),
+        ExtensionType::SnapshotAccount => Some(ExtensionType::SnapshotConfig),
+        ExtensionType::DistributionAccount => Some(ExtensionType::DistributionConfig),
+        ExtensionType::RoyaltyAccount => Some(ExtensionType::RoyaltyConfig),
+        ExtensionType::SoulBoundAccount => Some(ExtensionType::SoulBound),
+        ExtensionType::PausableAccount => Some(ExtensionType::PausableConfig),
+        _ => None,
+    }
+}
+
+/// Check that a mint's extensions are internally consistent, given its raw
+/// account data.
+///
+/// Fails if the data is not an initialized mint, if it holds an invalid
+/// combination of extensions, or if an extension's state is out of bounds.
+pub fn check_mint(mint_data: &[u8]) -> ProgramResult {
+    let mint = PodStateWithExtensions::<PodMint>::unpack(mint_data)?;
+    let mint_extension_types = mint.get_extension_types()?;
+    ExtensionType::check_for_invalid_mint_extension_combinations(&mint_extension_types)?;
+
+    if let Ok(extension) = mint.get_extension::<MaxSupplyConfig>() {
+        extension.check_supply(mint.base.supply.into())?;
+    }
+    if let Ok(extension) = mint.get_extension::<RoyaltyConfig>() {
+        extension.check_recipients()?;
+    }
+    Ok(())
+}
+
+/// Check that a token account's extensions are consistent with its mint's,
+/// given the raw data of both accounts.
+///
+/// Every account extension required by the mint's extensions must be
+/// present, and every account extension that belongs to a mint extension
+/// must have that extension on the mint. The mint itself is checked with
+/// `check_mint`.
+pub fn check_account(mint: &Pubkey, mint_data: &[u8], account_data: &[u8]) -> ProgramResult {
+    check_mint(mint_data)?;
+    let mint_state = PodStateWithExtensions::<PodMint>::unpack(mint_data)?;
+    let mint_extension_types = mint_state.get_extension_types()?;
+
+    let account = PodStateWithExtensions::<PodAccount>::unpack(account_data)?;
+    if account.base.mint != *mint {
+        return Err(TokenError::MintMismatch.into());
+    }
+    let account_extension_types = account.get_extension_types()?;
+
+    let required_extension_types =
+        ExtensionType::get_required_init_account_extensions(&mint_extension_types);
+    if required_extension_types
+        .iter()
+        .any(|extension_type| !account_extension_types.contains(extension_type))
+    {
+        return Err(TokenError::ExtensionTypeMismatch.into());
+    }
+    if account_extension_types
+        .iter()
+        .filter_map(get_mint_extension_for_account_extension)
+        .any(|extension_type| !mint_extension_types.contains(&extension_type))
+    {
+        return Err(TokenError::ExtensionTypeMismatch.into());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{
+            extension::{
+                confidential_transfer_fee::ConfidentialTransferFeeAmount, pausable::PausableConfig,
+                transfer_fee::TransferFeeConfig, BaseStateWithExtensionsMut,
+                PodStateWithExtensionsMut,
+            },
+            state::{Account, AccountState, Mint},
+        },
+        spl_pod::primitives::PodBool,
+    };
+
+    fn mint_data(extension_types: &[ExtensionType]) -> Vec<u8> {
+        let len = ExtensionType::try_calculate_account_len::<Mint>(extension_types).unwrap();
+        let mut data = vec![0; len];
+        let mut state =
+            PodStateWithExtensionsMut::<PodMint>::unpack_uninitialized(&mut data).unwrap();
+        for extension_type in extension_types {
+            match extension_type {
+                ExtensionType::PausableConfig => {
+                    state.init_extension::<PausableConfig>(true).unwrap();
+                }
+                ExtensionType::RoyaltyConfig => {
+                    state.init_extension::<RoyaltyConfig>(true).unwrap();
+                }
+                ExtensionType::TransferFeeConfig => {
+                    state.init_extension::<TransferFeeConfig>(true).unwrap();
+                }
+                _ => unreachable!(),
+            }
+        }
+        state.base.is_initialized = PodBool::from_bool(true);
+        state.init_account_type().unwrap();
+        data
+    }
+
+    fn account_data(mint: &Pubkey, extension_types: &[ExtensionType]) -> Vec<u8> {
+        let len = ExtensionType::try_calculate_account_len::<Account>(extension_types).unwrap();
+        let mut data = vec![0; len];
+        let mut state =
+            PodStateWithExtensionsMut::<PodAccount>::unpack_uninitialized(&mut data).unwrap();
+        for extension_type in extension_types {
+            match extension_type {
+                ExtensionType::ConfidentialTransferFeeAmount => {
+                    state
+                        .init_extension::<ConfidentialTransferFeeAmount>(true)
+                        .unwrap();
+                }
+                _ => state
+                    .init_account_extension_from_type(*extension_type)
+                    .unwrap(),
+            }
+        }
+        state.base.mint = *mint;
+        state.base.state = AccountState::Initialized.into();
+        state.init_account_type().unwrap();
+        data
+    }
+
+    #[test]
+    fn test_check_mint() {
+        assert_eq!(
+            check_mint(&mint_data(&[ExtensionType::PausableConfig])),
+            Ok(())
+        );
+        // a royalty config without recipients is out of bounds
+        assert_eq!(
+            check_mint(&mint_data(&[ExtensionType::RoyaltyConfig])),
+            Err(TokenError::InvalidRoyaltyRecipients.into())
+        );
+        assert!(check_mint(&[0; 10]).is_err());
+    }
+
+    #[test]
+    fn test_check_account() {
+        let mint = Pubkey::new_unique();
+        let pausable_mint = mint_data(&[ExtensionType::PausableConfig]);
+        let plain_mint = mint_data(&[]);
+
+        assert_eq!(
+            check_account(
+                &mint,
+                &pausable_mint,
+                &account_data(&mint, &[ExtensionType::PausableAccount])
+            ),
+            Ok(())
+        );
+        assert_eq!(
+            check_account(&mint, &plain_mint, &account_data(&mint, &[])),
+            Ok(())
+        );
+        // opt-in extensions with no mint counterpart are fine
+        assert_eq!(
+            check_account(
+                &mint,
+                &plain_mint,
+                &account_data(&mint, &[ExtensionType::ImmutableOwner])
+            ),
+            Ok(())
+        );
+
+        // required extension missing
+        assert_eq!(
+            check_account(&mint, &pausable_mint, &account_data(&mint, &[])),
+            Err(TokenError::ExtensionTypeMismatch.into())
+        );
+        // account extension without its mint extension
+        assert_eq!(
+            check_account(
+                &mint,
+                &plain_mint,
+                &account_data(&mint, &[ExtensionType::TransferFeeAmount])
+            ),
+            Err(TokenError::ExtensionTypeMismatch.into())
+        );
+        assert_eq!(
+            check_account(
+                &mint,
+                &pausable_mint,
+                &account_data(
+                    &mint,
+                    &[
+                        ExtensionType::PausableAccount,
+                        ExtensionType::NonTransferableAccount
+                    ]
+                )
+            ),
+            Err(TokenError::ExtensionTypeMismatch.into())
+        );
+        // the confidential fee amount belongs to the confidential fee config,
+        // not the transfer fee config
+        let transfer_fee_mint = mint_data(&[ExtensionType::TransferFeeConfig]);
+        assert_eq!(
+            check_account(
+                &mint,
+                &transfer_fee_mint,
+                &account_data(
+                    &mint,
+                    &[
+                        ExtensionType::TransferFeeAmount,
+                        ExtensionType::ConfidentialTransferFeeAmount
+                    ]
+                )
+            ),
+            Err(TokenError::ExtensionTypeMismatch.into())
+        );
+        // wrong mint
+        assert_eq!(
+            check_account(
+                &Pubkey::new_unique(),
+                &plain_mint,
+                &account_data(&mint, &[])
+            ),
+            Err(TokenError::MintMismatch.into())
+        );
+    }
+}
@@ -0,0 +1,192 @@
+#[cfg(feature = "serde-traits")]
+use serde::{Deserialize, Serialize};
+use {
+    crate::{
+        check_program_account,
+        extension::interest_bearing_mint::UnixTimestamp,
+        instruction::{encode_instruction, TokenInstruction},
+    },
+    bytemuck::{Pod, Zeroable},
+    num_enum::{IntoPrimitive, TryFromPrimitive},
+    solana_program::{
+        instruction::{AccountMeta, Instruction},
+        program_error::ProgramError,
+        pubkey::Pubkey,
+    },
+    spl_pod::{optional_keys::OptionalNonZeroPubkey, primitives::PodU64},
+    std::convert::TryInto,
+};
+
+/// Vesting extension instructions
+#[cfg_attr(feature = "serde-traits", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde-traits", serde(rename_all = "camelCase"))]
+#[derive(Clone, Copy, Debug, PartialEq, IntoPrimitive, TryFromPrimitive)]
+#[repr(u8)]
+pub enum VestingInstruction {
+    /// Initialize a new mint with a vesting authority.
+    ///
+    /// Fails if the mint has already been initialized, so must be called before
+    /// `InitializeMint`.
+    ///
+    /// The mint must have exactly enough space allocated for the base mint (82
+    /// bytes), plus 83 bytes of padding, 1 byte reserved for the account type,
+    /// then space required for this extension, plus any others.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` The mint to initialize.
+    ///
+    /// Data expected by this instruction:
+    ///   `crate::extension::vesting::instruction::InitializeInstructionData`
+    Initialize,
+    /// Create a vesting schedule on a token account, replacing any existing
+    /// schedule. Only supported for accounts of mints that include the
+    /// `VestingConfig` extension.
+    ///
+    /// Adds the `VestingAccount` extension to the account if it doesn't
+    /// already exist, in which case the account must first be reallocated by
+    /// its owner to make room for it.
+    ///
+    /// Fails if the schedule's timestamps are not ordered as
+    /// `start <= cliff <= end`.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single authority
+    ///   0. `[writable]` The token account.
+    ///   1. `[]` The token mint.
+    ///   2. `[signer]` The mint's vesting authority.
+    ///
+    ///   * Multisignature authority
+    ///   0. `[writable]` The token account.
+    ///   1. `[]` The token mint.
+    ///   2. `[]` The mint's multisignature vesting authority.
+    ///   3. ..3+M `[signer]` M signer accounts.
+    ///
+    /// Data expected by this instruction:
+    ///   `crate::extension::vesting::instruction::CreateScheduleInstructionData`
+    CreateSchedule,
+    /// Revoke the vesting schedule on a token account, unlocking all of its
+    /// tokens.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single authority
+    ///   0. `[writable]` The token account.
+    ///   1. `[]` The token mint.
+    ///   2. `[signer]` The mint's vesting authority.
+    ///
+    ///   * Multisignature authority
+    ///   0. `[writable]` The token account.
+    ///   1. `[]` The token mint.
+    ///   2. `[]` The mint's multisignature vesting authority.
+    ///   3. ..3+M `[signer]` M signer accounts.
+    RevokeSchedule,
+}
+
+/// Data expected by `VestingInstruction::Initialize`
+#[cfg_attr(feature = "serde-traits", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde-traits", serde(rename_all = "camelCase"))]
+#[derive(Clone, Copy, Pod, Zeroable)]
+#[repr(C)]
+pub struct InitializeInstructionData {
+    /// The public key for the account that can create and revoke schedules
+    pub authority: OptionalNonZeroPubkey,
+}
+
+/// Data expected by `VestingInstruction::CreateSchedule`
+#[cfg_attr(feature = "serde-traits", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde-traits", serde(rename_all = "camelCase"))]
+#[derive(Clone, Copy, Pod, Zeroable)]
+#[repr(C)]
+pub struct CreateScheduleInstructionData {
+    /// Amount of tokens subject to the schedule
+    pub total_amount: PodU64,
+    /// Timestamp from which tokens vest linearly
+    pub start_timestamp: UnixTimestamp,
+    /// Timestamp before which no tokens are vested
+    pub cliff_timestamp: UnixTimestamp,
+    /// Timestamp at which all tokens are vested
+    pub end_timestamp: UnixTimestamp,
+}
+
+/// Create an `Initialize` instruction
+pub fn initialize(
+    token_program_id: &Pubkey,
+    mint: &Pubkey,
+    authority: Option<Pubkey>,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    let accounts = vec![AccountMeta::new(*mint, false)];
+    Ok(encode_instruction(
+        token_program_id,
+        accounts,
+        TokenInstruction::VestingExtension,
+        VestingInstruction::Initialize,
+        &InitializeInstructionData {
+            authority: authority.try_into()?,
+        },
+    ))
+}
+
+/// Create a `CreateSchedule` instruction
+#[allow(clippy::too_many_arguments)]
+pub fn create_schedule(
+    token_program_id: &Pubkey,
+    account: &Pubkey,
+    mint: &Pubkey,
+    authority: &Pubkey,
+    signers: &[&Pubkey],
+    total_amount: u64,
+    start_timestamp: i64,
+    cliff_timestamp: i64,
+    end_timestamp: i64,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    let mut accounts = vec![
+        AccountMeta::new(*account, false),
+        AccountMeta::new_readonly(*mint, false),
+        AccountMeta::new_readonly(*authority, signers.is_empty()),
+    ];
+    for signer_pubkey in signers.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+    Ok(encode_instruction(
+        token_program_id,
+        accounts,
+        TokenInstruction::VestingExtension,
+        VestingInstruction::CreateSchedule,
+        &CreateScheduleInstructionData {
+            total_amount: total_amount.into(),
+            start_timestamp: start_timestamp.into(),
+            cliff_timestamp: cliff_timestamp.into(),
+            end_timestamp: end_timestamp.into(),
+        },
+    ))
+}
+
+/// Create a `RevokeSchedule` instruction
+pub fn revoke_schedule(
+    token_program_id: &Pubkey,
+    account: &Pubkey,
+    mint: &Pubkey,
+    authority: &Pubkey,
+    signers: &[&Pubkey],
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    let mut accounts = vec![
+        AccountMeta::new(*account, false),
+        AccountMeta::new_readonly(*mint, false),
+        AccountMeta::new_readonly(*authority, signers.is_empty()),
+    ];
+    for signer_pubkey in signers.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+    Ok(encode_instruction(
+        token_program_id,
+        accounts,
+        TokenInstruction::VestingExtension,
+        VestingInstruction::RevokeSchedule,
+        &(),
+    ))
+}
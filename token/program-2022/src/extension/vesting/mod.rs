@@ -0,0 +1,168 @@
+#[cfg(feature = "serde-traits")]
+use serde::{Deserialize, Serialize};
+use {
+    crate::{
+        error::TokenError,
+        extension::{interest_bearing_mint::UnixTimestamp, Extension, ExtensionType},
+    },
+    bytemuck::{Pod, Zeroable},
+    solana_program::entrypoint::ProgramResult,
+    spl_pod::{optional_keys::OptionalNonZeroPubkey, primitives::PodU64},
+    std::convert::TryFrom,
+};
+
+/// Vesting extension instructions
+pub mod instruction;
+
+/// Vesting extension processor
+pub mod processor;
+
+/// Vesting extension data for mints
+#[repr(C)]
+#[cfg_attr(feature = "serde-traits", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde-traits", serde(rename_all = "camelCase"))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+pub struct VestingConfig {
+    /// Authority that can create and revoke vesting schedules on accounts
+    pub authority: OptionalNonZeroPubkey,
+}
+impl Extension for VestingConfig {
+    const TYPE: ExtensionType = ExtensionType::VestingConfig;
+}
+
+/// Vesting extension data for accounts
+///
+/// Nothing vests before `cliff_timestamp`. From then on, `total_amount` vests
+/// linearly over the period from `start_timestamp` to `end_timestamp`, so the
+/// cliff releases everything accrued since the start at once.
+#[repr(C)]
+#[cfg_attr(feature = "serde-traits", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde-traits", serde(rename_all = "camelCase"))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, Zeroable)]
+pub struct VestingAccount {
+    /// Amount of tokens subject to the schedule
+    pub total_amount: PodU64,
+    /// Timestamp from which tokens vest linearly
+    pub start_timestamp: UnixTimestamp,
+    /// Timestamp before which no tokens are vested
+    pub cliff_timestamp: UnixTimestamp,
+    /// Timestamp at which all tokens are vested
+    pub end_timestamp: UnixTimestamp,
+}
+impl VestingAccount {
+    /// Check that the schedule's timestamps are ordered
+    pub fn is_valid(&self) -> bool {
+        i64::from(self.start_timestamp) <= i64::from(self.cliff_timestamp)
+            && i64::from(self.cliff_timestamp) <= i64::from(self.end_timestamp)
+    }
+
+    /// Amount of tokens vested at the given timestamp
+    pub fn vested_amount(&self, unix_timestamp: i64) -> Option<u64> {
+        let total_amount = u64::from(self.total_amount);
+        let start_timestamp = i64::from(self.start_timestamp);
+        let end_timestamp = i64::from(self.end_timestamp);
+        if unix_timestamp < i64::from(self.cliff_timestamp) {
+            Some(0)
+        } else if unix_timestamp >= end_timestamp {
+            Some(total_amount)
+        } else {
+            // widen so that any ordered pair of i64 timestamps has a
+            // representable difference
+            let elapsed = (unix_timestamp as i128).checked_sub(start_timestamp as i128)?;
+            let duration = (end_timestamp as i128).checked_sub(start_timestamp as i128)?;
+            let vested = (total_amount as u128)
+                .checked_mul(u128::try_from(elapsed).ok()?)?
+                .checked_div(u128::try_from(duration).ok()?)?;
+            u64::try_from(vested).ok()
+        }
+    }
+
+    /// Amount of tokens still locked at the given timestamp
+    pub fn locked_amount(&self, unix_timestamp: i64) -> Option<u64> {
+        u64::from(self.total_amount).checked_sub(self.vested_amount(unix_timestamp)?)
+    }
+
+    /// Check that `amount` can be moved out of an account holding `balance`
+    /// without touching locked tokens
+    pub fn check_available(&self, balance: u64, amount: u64, unix_timestamp: i64) -> ProgramResult {
+        let locked_amount = self
+            .locked_amount(unix_timestamp)
+            .ok_or(TokenError::Overflow)?;
+        if balance.saturating_sub(locked_amount) < amount {
+            Err(TokenError::InsufficientVestedFunds.into())
+        } else {
+            Ok(())
+        }
+    }
+}
+impl Extension for VestingAccount {
+    const TYPE: ExtensionType = ExtensionType::VestingAccount;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn schedule(total_amount: u64, start: i64, cliff: i64, end: i64) -> VestingAccount {
+        VestingAccount {
+            total_amount: total_amount.into(),
+            start_timestamp: start.into(),
+            cliff_timestamp: cliff.into(),
+            end_timestamp: end.into(),
+        }
+    }
+
+    #[test]
+    fn validity() {
+        assert!(schedule(100, 0, 0, 0).is_valid());
+        assert!(schedule(100, 0, 10, 100).is_valid());
+        assert!(schedule(100, 0, 100, 100).is_valid());
+        assert!(!schedule(100, 10, 0, 100).is_valid());
+        assert!(!schedule(100, 0, 100, 10).is_valid());
+    }
+
+    #[test]
+    fn cliff_and_linear_vesting() {
+        let vesting = schedule(1_000, 100, 200, 1_100);
+        assert_eq!(vesting.vested_amount(0), Some(0));
+        assert_eq!(vesting.vested_amount(199), Some(0));
+        // the cliff releases everything accrued since the start
+        assert_eq!(vesting.vested_amount(200), Some(100));
+        assert_eq!(vesting.vested_amount(600), Some(500));
+        assert_eq!(vesting.vested_amount(1_099), Some(999));
+        assert_eq!(vesting.vested_amount(1_100), Some(1_000));
+        assert_eq!(vesting.vested_amount(i64::MAX), Some(1_000));
+        assert_eq!(vesting.locked_amount(600), Some(500));
+    }
+
+    #[test]
+    fn instant_vesting() {
+        let vesting = schedule(1_000, 50, 50, 50);
+        assert_eq!(vesting.vested_amount(49), Some(0));
+        assert_eq!(vesting.vested_amount(50), Some(1_000));
+    }
+
+    #[test]
+    fn no_overflow() {
+        let vesting = schedule(u64::MAX, 0, 0, i64::MAX);
+        assert_eq!(vesting.vested_amount(i64::MAX - 1), Some(u64::MAX - 3));
+        // the schedule's duration does not fit in an i64
+        let vesting = schedule(u64::MAX, i64::MIN, i64::MIN, i64::MAX);
+        assert_eq!(vesting.vested_amount(0), Some(1 << 63));
+        assert!(vesting.check_available(u64::MAX, 1 << 63, 0).is_ok());
+    }
+
+    #[test]
+    fn available_amount() {
+        let vesting = schedule(1_000, 0, 0, 1_000);
+        // half vested, half locked
+        assert!(vesting.check_available(1_000, 500, 500).is_ok());
+        assert_eq!(
+            vesting.check_available(1_000, 501, 500),
+            Err(TokenError::InsufficientVestedFunds.into())
+        );
+        // tokens received outside of the schedule are always available
+        assert!(vesting.check_available(1_200, 700, 500).is_ok());
+        assert!(vesting.check_available(1_000, 1_000, 1_000).is_ok());
+    }
+}
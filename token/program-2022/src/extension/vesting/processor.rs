@@ -0,0 +1,124 @@
+use {
+    crate::{
+        check_program_account,
+        error::TokenError,
+        extension::{
+            vesting::{
+                instruction::{
+                    CreateScheduleInstructionData, InitializeInstructionData, VestingInstruction,
+                },
+                VestingAccount, VestingConfig,
+            },
+            BaseStateWithExtensions, BaseStateWithExtensionsMut, PodStateWithExtensions,
+            PodStateWithExtensionsMut,
+        },
+        instruction::{decode_instruction_data, decode_instruction_type},
+        pod::{PodAccount, PodMint},
+        processor::Processor,
+    },
+    solana_program::{
+        account_info::{next_account_info, AccountInfo},
+        entrypoint::ProgramResult,
+        msg,
+        pubkey::Pubkey,
+    },
+    spl_pod::optional_keys::OptionalNonZeroPubkey,
+};
+
+fn process_initialize(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    authority: &OptionalNonZeroPubkey,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let mint_account_info = next_account_info(account_info_iter)?;
+    let mut mint_data = mint_account_info.data.borrow_mut();
+    let mut mint = PodStateWithExtensionsMut::<PodMint>::unpack_uninitialized(&mut mint_data)?;
+
+    let extension = mint.init_extension::<VestingConfig>(true)?;
+    extension.authority = *authority;
+    Ok(())
+}
+
+/// Write the given schedule to the token account, after checking the mint's
+/// vesting authority, initializing the extension if not already present.
+fn process_set_schedule(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    schedule: VestingAccount,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+    let token_account_info = next_account_info(account_info_iter)?;
+    let mint_info = next_account_info(account_info_iter)?;
+    let authority_info = next_account_info(account_info_iter)?;
+    let authority_info_data_len = authority_info.data_len();
+
+    let mut token_account_data = token_account_info.data.borrow_mut();
+    let mut token_account =
+        PodStateWithExtensionsMut::<PodAccount>::unpack(&mut token_account_data)?;
+    if token_account.base.mint != *mint_info.key {
+        return Err(TokenError::MintMismatch.into());
+    }
+
+    check_program_account(mint_info.owner)?;
+    let mint_data = mint_info.data.borrow();
+    let mint = PodStateWithExtensions::<PodMint>::unpack(&mint_data)?;
+    let extension = mint.get_extension::<VestingConfig>()?;
+    let authority =
+        Option::<Pubkey>::from(extension.authority).ok_or(TokenError::NoAuthorityExists)?;
+
+    Processor::validate_owner(
+        program_id,
+        &authority,
+        authority_info,
+        authority_info_data_len,
+        account_info_iter.as_slice(),
+    )?;
+
+    let extension = if let Ok(extension) = token_account.get_extension_mut::<VestingAccount>() {
+        extension
+    } else {
+        token_account.init_extension::<VestingAccount>(true)?
+    };
+    *extension = schedule;
+    Ok(())
+}
+
+pub(crate) fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    input: &[u8],
+) -> ProgramResult {
+    check_program_account(program_id)?;
+    match decode_instruction_type(input)? {
+        VestingInstruction::Initialize => {
+            msg!("VestingInstruction::Initialize");
+            let InitializeInstructionData { authority } = decode_instruction_data(input)?;
+            process_initialize(program_id, accounts, authority)
+        }
+        VestingInstruction::CreateSchedule => {
+            msg!("VestingInstruction::CreateSchedule");
+            let CreateScheduleInstructionData {
+                total_amount,
+                start_timestamp,
+                cliff_timestamp,
+                end_timestamp,
+            } = decode_instruction_data(input)?;
+            let schedule = VestingAccount {
+                total_amount: *total_amount,
+                start_timestamp: *start_timestamp,
+                cliff_timestamp: *cliff_timestamp,
+                end_timestamp: *end_timestamp,
+            };
+            if !schedule.is_valid() {
+                return Err(TokenError::InvalidVestingSchedule.into());
+            }
+            process_set_schedule(program_id, accounts, schedule)
+        }
+        VestingInstruction::RevokeSchedule => {
+            msg!("VestingInstruction::RevokeSchedule");
+            // An empty schedule never locks any tokens
+            process_set_schedule(program_id, accounts, VestingAccount::default())
+        }
+    }
+}
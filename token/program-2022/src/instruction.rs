@@ -708,6 +708,90 @@ pub enum TokenInstruction<'a> {
     /// for further details about the extended instructions that share this
     /// instruction prefix
     GroupMemberPointerExtension,
+    /// The common instruction prefix for max supply extension instructions.
+    ///
+    /// See `extension::max_supply::instruction::MaxSupplyInstruction` for
+    /// further details about the extended instructions that share this
+    /// instruction prefix
+    MaxSupplyExtension,
+    /// The common instruction prefix for vesting extension instructions.
+    ///
+    /// See `extension::vesting::instruction::VestingInstruction` for further
+    /// details about the extended instructions that share this instruction
+    /// prefix
+    VestingExtension,
+    /// The common instruction prefix for transfer restriction extension
+    /// instructions.
+    ///
+    /// See `extension::transfer_restriction::instruction::TransferRestrictionInstruction`
+    /// for further details about the extended instructions that share this
+    /// instruction prefix
+    TransferRestrictionExtension,
+    /// The common instruction prefix for transfer limit extension
+    /// instructions.
+    ///
+    /// See `extension::transfer_limit::instruction::TransferLimitInstruction`
+    /// for further details about the extended instructions that share this
+    /// instruction prefix
+    TransferLimitExtension,
+    /// The common instruction prefix for snapshot extension instructions.
+    ///
+    /// See `extension::snapshot::instruction::SnapshotInstruction` for
+    /// further details about the extended instructions that share this
+    /// instruction prefix
+    SnapshotExtension,
+    /// The common instruction prefix for distribution extension
+    /// instructions.
+    ///
+    /// See `extension::distribution::instruction::DistributionInstruction`
+    /// for further details about the extended instructions that share this
+    /// instruction prefix
+    DistributionExtension,
+    /// The common instruction prefix for approval expiry extension
+    /// instructions.
+    ///
+    /// See `extension::approval_expiry::instruction::ApprovalExpiryInstruction`
+    /// for further details about the extended instructions that share this
+    /// instruction prefix
+    ApprovalExpiryExtension,
+    /// The common instruction prefix for secondary authority extension
+    /// instructions.
+    ///
+    /// See `extension::secondary_authority::instruction::SecondaryAuthorityInstruction`
+    /// for further details about the extended instructions that share this
+    /// instruction prefix
+    SecondaryAuthorityExtension,
+    /// The common instruction prefix for royalty extension instructions.
+    ///
+    /// See `extension::royalty::instruction::RoyaltyInstruction` for
+    /// further details about the extended instructions that share this
+    /// instruction prefix
+    RoyaltyExtension,
+    /// The common instruction prefix for timed freeze extension instructions.
+    ///
+    /// See `extension::timed_freeze::instruction::TimedFreezeInstruction` for
+    /// further details about the extended instructions that share this
+    /// instruction prefix
+    TimedFreezeExtension,
+    /// The common instruction prefix for soul-bound extension instructions.
+    ///
+    /// See `extension::soul_bound::instruction::SoulBoundInstruction` for
+    /// further details about the extended instructions that share this
+    /// instruction prefix
+    SoulBoundExtension,
+    /// The common instruction prefix for pending transfer extension
+    /// instructions.
+    ///
+    /// See `extension::pending_transfer::instruction::PendingTransferInstruction`
+    /// for further details about the extended instructions that share this
+    /// instruction prefix
+    PendingTransferExtension,
+    /// The common instruction prefix for pausable extension instructions.
+    ///
+    /// See `extension::pausable::instruction::PausableInstruction` for
+    /// further details about the extended instructions that share this
+    /// instruction prefix
+    PausableExtension,
 }
 impl<'a> TokenInstruction<'a> {
     /// Unpacks a byte buffer into a
@@ -847,6 +931,19 @@ impl<'a> TokenInstruction<'a> {
             39 => Self::MetadataPointerExtension,
             40 => Self::GroupPointerExtension,
             41 => Self::GroupMemberPointerExtension,
+            42 => Self::MaxSupplyExtension,
+            43 => Self::VestingExtension,
+            44 => Self::TransferRestrictionExtension,
+            45 => Self::TransferLimitExtension,
+            46 => Self::SnapshotExtension,
+            47 => Self::DistributionExtension,
+            48 => Self::ApprovalExpiryExtension,
+            49 => Self::SecondaryAuthorityExtension,
+            50 => Self::RoyaltyExtension,
+            51 => Self::TimedFreezeExtension,
+            52 => Self::SoulBoundExtension,
+            53 => Self::PendingTransferExtension,
+            54 => Self::PausableExtension,
             _ => return Err(TokenError::InvalidInstruction.into()),
         })
     }
@@ -1018,6 +1115,45 @@ impl<'a> TokenInstruction<'a> {
             &Self::GroupMemberPointerExtension => {
                 buf.push(41);
             }
+            &Self::MaxSupplyExtension => {
+                buf.push(42);
+            }
+            &Self::VestingExtension => {
+                buf.push(43);
+            }
+            &Self::TransferRestrictionExtension => {
+                buf.push(44);
+            }
+            &Self::TransferLimitExtension => {
+                buf.push(45);
+            }
+            &Self::SnapshotExtension => {
+                buf.push(46);
+            }
+            &Self::DistributionExtension => {
+                buf.push(47);
+            }
+            &Self::ApprovalExpiryExtension => {
+                buf.push(48);
+            }
+            &Self::SecondaryAuthorityExtension => {
+                buf.push(49);
+            }
+            &Self::RoyaltyExtension => {
+                buf.push(50);
+            }
+            &Self::TimedFreezeExtension => {
+                buf.push(51);
+            }
+            &Self::SoulBoundExtension => {
+                buf.push(52);
+            }
+            &Self::PendingTransferExtension => {
+                buf.push(53);
+            }
+            &Self::PausableExtension => {
+                buf.push(54);
+            }
         };
         buf
     }
@@ -1115,6 +1251,22 @@ pub enum AuthorityType {
     GroupPointer,
     /// Authority to set the group member address
     GroupMemberPointer,
+    /// Authority to set the maximum supply
+    MaxSupply,
+    /// Authority to create and revoke vesting schedules
+    Vesting,
+    /// Authority to update the transfer allowlist and denylist
+    TransferRestriction,
+    /// Authority to update the transfer limits and override list
+    TransferLimit,
+    /// Authority to create balance snapshots
+    Snapshot,
+    /// Authority to fund reward distributions
+    Distribution,
+    /// Authority to update the royalty recipients
+    Royalty,
+    /// Authority to pause and resume the mint
+    Pause,
 }
 
 impl AuthorityType {
@@ -1135,6 +1287,14 @@ impl AuthorityType {
             AuthorityType::MetadataPointer => 12,
             AuthorityType::GroupPointer => 13,
             AuthorityType::GroupMemberPointer => 14,
+            AuthorityType::MaxSupply => 15,
+            AuthorityType::Vesting => 16,
+            AuthorityType::TransferRestriction => 17,
+            AuthorityType::TransferLimit => 18,
+            AuthorityType::Snapshot => 19,
+            AuthorityType::Distribution => 20,
+            AuthorityType::Royalty => 21,
+            AuthorityType::Pause => 22,
         }
     }
 
@@ -1155,6 +1315,14 @@ impl AuthorityType {
             12 => Ok(AuthorityType::MetadataPointer),
             13 => Ok(AuthorityType::GroupPointer),
             14 => Ok(AuthorityType::GroupMemberPointer),
+            15 => Ok(AuthorityType::MaxSupply),
+            16 => Ok(AuthorityType::Vesting),
+            17 => Ok(AuthorityType::TransferRestriction),
+            18 => Ok(AuthorityType::TransferLimit),
+            19 => Ok(AuthorityType::Snapshot),
+            20 => Ok(AuthorityType::Distribution),
+            21 => Ok(AuthorityType::Royalty),
+            22 => Ok(AuthorityType::Pause),
             _ => Err(TokenError::InvalidInstruction.into()),
         }
     }
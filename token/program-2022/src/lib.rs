@@ -8,6 +8,7 @@ pub mod error;
 pub mod extension;
 pub mod generic_token_account;
 pub mod instruction;
+pub mod mint_builder;
 pub mod native_mint;
 pub mod offchain;
 pub mod onchain;
@@ -18,6 +19,7 @@ pub mod proof;
 #[cfg(feature = "serde-traits")]
 pub mod serialization;
 pub mod state;
+pub mod summary;
 
 #[cfg(not(feature = "no-entrypoint"))]
 mod entrypoint;
@@ -71,6 +73,41 @@ pub fn amount_to_ui_amount_string_trimmed(amount: u64, decimals: u8) -> String {
     s
 }
 
+/// Options for `format_ui_amount`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct UiAmountFormat {
+    /// Group the integer part in thousands with `,`
+    pub thousands_separator: bool,
+    /// Trim excess zeroes and an unneeded decimal point
+    pub trim_trailing_zeros: bool,
+}
+
+/// Convert a raw amount to its UI representation using the given decimals field
+/// and format options.
+///
+/// The result only uses integer arithmetic, so it never contains an exponent,
+/// and always uses `.` as the decimal point regardless of locale.
+pub fn format_ui_amount(amount: u64, decimals: u8, format: UiAmountFormat) -> String {
+    let s = if format.trim_trailing_zeros {
+        amount_to_ui_amount_string_trimmed(amount, decimals)
+    } else {
+        amount_to_ui_amount_string(amount, decimals)
+    };
+    if !format.thousands_separator {
+        return s;
+    }
+    let (integer, fraction) = s.split_at(s.find('.').unwrap_or(s.len()));
+    let mut grouped = String::with_capacity(s.len() + integer.len() / 3);
+    for (i, digit) in integer.chars().enumerate() {
+        if i > 0 && (integer.len() - i) % 3 == 0 {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+    grouped.push_str(fraction);
+    grouped
+}
+
 /// Try to convert a UI representation of a token amount to its raw amount using
 /// the given decimals field
 pub fn try_ui_amount_into_amount(ui_amount: String, decimals: u8) -> Result<u64, ProgramError> {
@@ -138,3 +175,45 @@ pub fn check_system_program_account(system_program_id: &Pubkey) -> ProgramResult
 pub fn cmp_pubkeys(a: &Pubkey, b: &Pubkey) -> bool {
     sol_memcmp(a.as_ref(), b.as_ref(), PUBKEY_BYTES) == 0
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_ui_amount() {
+        let plain = UiAmountFormat::default();
+        let trimmed = UiAmountFormat {
+            trim_trailing_zeros: true,
+            ..UiAmountFormat::default()
+        };
+        let grouped = UiAmountFormat {
+            thousands_separator: true,
+            trim_trailing_zeros: true,
+        };
+
+        assert_eq!(format_ui_amount(1_500_000, 6, plain), "1.500000");
+        assert_eq!(format_ui_amount(1_500_000, 6, trimmed), "1.5");
+        assert_eq!(format_ui_amount(1_000_000, 6, trimmed), "1");
+        assert_eq!(format_ui_amount(0, 9, plain), "0.000000000");
+        assert_eq!(format_ui_amount(0, 9, trimmed), "0");
+        assert_eq!(format_ui_amount(1, 9, trimmed), "0.000000001");
+        assert_eq!(format_ui_amount(100, 0, trimmed), "100");
+
+        // no exponent, even at the extremes
+        assert_eq!(format_ui_amount(u64::MAX, 0, plain), "18446744073709551615");
+        assert_eq!(
+            format_ui_amount(1, 30, trimmed),
+            "0.000000000000000000000000000001"
+        );
+
+        assert_eq!(format_ui_amount(999, 0, grouped), "999");
+        assert_eq!(format_ui_amount(1_000, 0, grouped), "1,000");
+        assert_eq!(format_ui_amount(1_234_567_890, 3, grouped), "1,234,567.89");
+        assert_eq!(
+            format_ui_amount(u64::MAX, 9, grouped),
+            "18,446,744,073.709551615"
+        );
+        assert_eq!(format_ui_amount(5, 2, grouped), "0.05");
+    }
+}
@@ -0,0 +1,527 @@
+//! Builder for the instructions that create a mint with extensions
+
+use {
+    crate::{
+        error::TokenError,
+        extension::{
+            confidential_transfer, confidential_transfer_fee, default_account_state, distribution,
+            group_member_pointer, group_pointer, interest_bearing_mint, max_supply,
+            metadata_pointer, pausable, royalty, snapshot, soul_bound, transfer_fee, transfer_hook,
+            transfer_limit, transfer_restriction, vesting, ExtensionType,
+        },
+        instruction::{
+            initialize_mint2, initialize_mint_close_authority, initialize_non_transferable_mint,
+            initialize_permanent_delegate,
+        },
+        solana_zk_token_sdk::zk_token_elgamal::pod::ElGamalPubkey,
+        state::{AccountState, Mint},
+    },
+    solana_program::{
+        instruction::Instruction, program_error::ProgramError, pubkey::Pubkey, rent::Rent,
+        system_instruction,
+    },
+};
+
+/// Initialization parameters for a mint extension
+#[derive(Clone, Debug, PartialEq)]
+pub enum MintExtension {
+    /// `ConfidentialTransferMint`
+    ConfidentialTransferMint {
+        /// Authority to modify the confidential transfer configuration
+        authority: Option<Pubkey>,
+        /// Whether new accounts are approved for confidential transfers
+        auto_approve_new_accounts: bool,
+        /// Auditor ElGamal public key
+        auditor_elgamal_pubkey: Option<ElGamalPubkey>,
+    },
+    /// `DefaultAccountState`
+    DefaultAccountState {
+        /// State of new token accounts
+        state: AccountState,
+    },
+    /// `MintCloseAuthority`
+    MintCloseAuthority {
+        /// Authority that can close the mint
+        close_authority: Option<Pubkey>,
+    },
+    /// `TransferFeeConfig`
+    TransferFeeConfig {
+        /// Authority that can set the fee
+        transfer_fee_config_authority: Option<Pubkey>,
+        /// Authority that can withdraw withheld fees
+        withdraw_withheld_authority: Option<Pubkey>,
+        /// Fee in basis points of the transfer amount
+        transfer_fee_basis_points: u16,
+        /// Maximum fee per transfer
+        maximum_fee: u64,
+    },
+    /// `InterestBearingConfig`
+    InterestBearingConfig {
+        /// Authority that can set the interest rate
+        rate_authority: Option<Pubkey>,
+        /// Interest rate in basis points
+        rate: i16,
+    },
+    /// `NonTransferable`
+    NonTransferable,
+    /// `PermanentDelegate`
+    PermanentDelegate {
+        /// Delegate for every account of the mint
+        delegate: Pubkey,
+    },
+    /// `TransferHook`
+    TransferHook {
+        /// Authority that can set the transfer hook program id
+        authority: Option<Pubkey>,
+        /// Program called on every transfer
+        program_id: Option<Pubkey>,
+    },
+    /// `MetadataPointer`
+    MetadataPointer {
+        /// Authority that can set the metadata address
+        authority: Option<Pubkey>,
+        /// Account holding the metadata
+        metadata_address: Option<Pubkey>,
+    },
+    /// `ConfidentialTransferFeeConfig`
+    ConfidentialTransferFeeConfig {
+        /// Authority to modify the confidential transfer fee configuration
+        authority: Option<Pubkey>,
+        /// ElGamal public key for encrypting withheld fees
+        withdraw_withheld_authority_elgamal_pubkey: ElGamalPubkey,
+    },
+    /// `GroupPointer`
+    GroupPointer {
+        /// Authority that can set the group address
+        authority: Option<Pubkey>,
+        /// Account holding the group
+        group_address: Option<Pubkey>,
+    },
+    /// `GroupMemberPointer`
+    GroupMemberPointer {
+        /// Authority that can set the member address
+        authority: Option<Pubkey>,
+        /// Account holding the group member
+        member_address: Option<Pubkey>,
+    },
+    /// `MaxSupplyConfig`
+    MaxSupplyConfig {
+        /// Authority that can change the maximum supply
+        authority: Option<Pubkey>,
+        /// Maximum supply of the mint
+        max_supply: u64,
+    },
+    /// `VestingConfig`
+    VestingConfig {
+        /// Authority that can set vesting schedules
+        authority: Option<Pubkey>,
+    },
+    /// `TransferRestrictionConfig`
+    TransferRestrictionConfig {
+        /// Authority that can manage the allowlist
+        authority: Option<Pubkey>,
+        /// Whether accounts must be allowlisted to transfer
+        allowlist_required: bool,
+    },
+    /// `TransferLimitConfig`
+    TransferLimitConfig {
+        /// Authority that can change the limits
+        authority: Option<Pubkey>,
+        /// Maximum amount per transfer
+        max_per_transfer: u64,
+        /// Maximum amount transferred per account per day
+        daily_cap: u64,
+    },
+    /// `SnapshotConfig`
+    SnapshotConfig {
+        /// Authority that can create snapshots
+        authority: Option<Pubkey>,
+    },
+    /// `DistributionConfig`
+    DistributionConfig {
+        /// Authority that can fund distributions
+        authority: Option<Pubkey>,
+    },
+    /// `RoyaltyConfig`
+    RoyaltyConfig {
+        /// Authority that can update the recipients
+        authority: Option<Pubkey>,
+        /// Destination accounts and their shares in basis points
+        recipients: Vec<(Pubkey, u16)>,
+    },
+    /// `SoulBound`
+    SoulBound,
+    /// `PausableConfig`
+    PausableConfig {
+        /// Authority that can pause and resume the mint
+        authority: Option<Pubkey>,
+    },
+}
+impl MintExtension {
+    /// Get the extension type associated with the init params
+    pub fn extension(&self) -> ExtensionType {
+        match self {
+            Self::ConfidentialTransferMint { .. } => ExtensionType::ConfidentialTransferMint,
+            Self::DefaultAccountState { .. } => ExtensionType::DefaultAccountState,
+            Self::MintCloseAuthority { .. } => ExtensionType::MintCloseAuthority,
+            Self::TransferFeeConfig { .. } => ExtensionType::TransferFeeConfig,
+            Self::InterestBearingConfig { .. } => ExtensionType::InterestBearingConfig,
+            Self::NonTransferable => ExtensionType::NonTransferable,
+            Self::PermanentDelegate { .. } => ExtensionType::PermanentDelegate,
+            Self::TransferHook { .. } => ExtensionType::TransferHook,
+            Self::MetadataPointer { .. } => ExtensionType::MetadataPointer,
+            Self::ConfidentialTransferFeeConfig { .. } => {
+                ExtensionType::ConfidentialTransferFeeConfig
+            }
+            Self::GroupPointer { .. } => ExtensionType::GroupPointer,
+            Self::GroupMemberPointer { .. } => ExtensionType::GroupMemberPointer,
+            Self::MaxSupplyConfig { .. } => ExtensionType::MaxSupplyConfig,
+            Self::VestingConfig { .. } => ExtensionType::VestingConfig,
+            Self::TransferRestrictionConfig { .. } => ExtensionType::TransferRestrictionConfig,
+            Self::TransferLimitConfig { .. } => ExtensionType::TransferLimitConfig,
+            Self::SnapshotConfig { .. } => ExtensionType::SnapshotConfig,
+            Self::DistributionConfig { .. } => ExtensionType::DistributionConfig,
+            Self::RoyaltyConfig { .. } => ExtensionType::RoyaltyConfig,
+            Self::SoulBound => ExtensionType::SoulBound,
+            Self::PausableConfig { .. } => ExtensionType::PausableConfig,
+        }
+    }
+
+    /// Create the instruction initializing the extension on the given mint
+    pub fn instruction(
+        self,
+        token_program_id: &Pubkey,
+        mint: &Pubkey,
+    ) -> Result<Instruction, ProgramError> {
+        match self {
+            Self::ConfidentialTransferMint {
+                authority,
+                auto_approve_new_accounts,
+                auditor_elgamal_pubkey,
+            } => confidential_transfer::instruction::initialize_mint(
+                token_program_id,
+                mint,
+                authority,
+                auto_approve_new_accounts,
+                auditor_elgamal_pubkey,
+            ),
+            Self::DefaultAccountState { state } => {
+                default_account_state::instruction::initialize_default_account_state(
+                    token_program_id,
+                    mint,
+                    &state,
+                )
+            }
+            Self::MintCloseAuthority { close_authority } => {
+                initialize_mint_close_authority(token_program_id, mint, close_authority.as_ref())
+            }
+            Self::TransferFeeConfig {
+                transfer_fee_config_authority,
+                withdraw_withheld_authority,
+                transfer_fee_basis_points,
+                maximum_fee,
+            } => transfer_fee::instruction::initialize_transfer_fee_config(
+                token_program_id,
+                mint,
+                transfer_fee_config_authority.as_ref(),
+                withdraw_withheld_authority.as_ref(),
+                transfer_fee_basis_points,
+                maximum_fee,
+            ),
+            Self::InterestBearingConfig {
+                rate_authority,
+                rate,
+            } => interest_bearing_mint::instruction::initialize(
+                token_program_id,
+                mint,
+                rate_authority,
+                rate,
+            ),
+            Self::NonTransferable => initialize_non_transferable_mint(token_program_id, mint),
+            Self::PermanentDelegate { delegate } => {
+                initialize_permanent_delegate(token_program_id, mint, &delegate)
+            }
+            Self::TransferHook {
+                authority,
+                program_id,
+            } => transfer_hook::instruction::initialize(
+                token_program_id,
+                mint,
+                authority,
+                program_id,
+            ),
+            Self::MetadataPointer {
+                authority,
+                metadata_address,
+            } => metadata_pointer::instruction::initialize(
+                token_program_id,
+                mint,
+                authority,
+                metadata_address,
+            ),
+            Self::ConfidentialTransferFeeConfig {
+                authority,
+                withdraw_withheld_authority_elgamal_pubkey,
+            } => {
+                confidential_transfer_fee::instruction::initialize_confidential_transfer_fee_config(
+                    token_program_id,
+                    mint,
+                    authority,
+                    withdraw_withheld_authority_elgamal_pubkey,
+                )
+            }
+            Self::GroupPointer {
+                authority,
+                group_address,
+            } => group_pointer::instruction::initialize(
+                token_program_id,
+                mint,
+                authority,
+                group_address,
+            ),
+            Self::GroupMemberPointer {
+                authority,
+                member_address,
+            } => group_member_pointer::instruction::initialize(
+                token_program_id,
+                mint,
+                authority,
+                member_address,
+            ),
+            Self::MaxSupplyConfig {
+                authority,
+                max_supply,
+            } => max_supply::instruction::initialize(token_program_id, mint, authority, max_supply),
+            Self::VestingConfig { authority } => {
+                vesting::instruction::initialize(token_program_id, mint, authority)
+            }
+            Self::TransferRestrictionConfig {
+                authority,
+                allowlist_required,
+            } => transfer_restriction::instruction::initialize(
+                token_program_id,
+                mint,
+                authority,
+                allowlist_required,
+            ),
+            Self::TransferLimitConfig {
+                authority,
+                max_per_transfer,
+                daily_cap,
+            } => transfer_limit::instruction::initialize(
+                token_program_id,
+                mint,
+                authority,
+                max_per_transfer,
+                daily_cap,
+            ),
+            Self::SnapshotConfig { authority } => {
+                snapshot::instruction::initialize(token_program_id, mint, authority)
+            }
+            Self::DistributionConfig { authority } => {
+                distribution::instruction::initialize(token_program_id, mint, authority)
+            }
+            Self::RoyaltyConfig {
+                authority,
+                recipients,
+            } => royalty::instruction::initialize(token_program_id, mint, authority, &recipients),
+            Self::SoulBound => soul_bound::instruction::initialize(token_program_id, mint),
+            Self::PausableConfig { authority } => {
+                pausable::instruction::initialize(token_program_id, mint, authority)
+            }
+        }
+    }
+}
+
+/// Collects a mint's extensions and emits the instructions to create it.
+///
+/// The mint account is created with exactly enough space for every extension,
+/// then each extension is initialized in the order it was added, and finally
+/// the mint itself is initialized. Can be used in the following way:
+///
+/// ```rust,ignore
+/// let instructions = MintBuilder::new(&spl_token_2022::id(), &mint, &mint_authority, 6)
+///     .freeze_authority(&freeze_authority)
+///     .extension(MintExtension::MaxSupplyConfig {
+///         authority: None,
+///         max_supply: 1_000_000,
+///     })?
+///     .build(&payer, &Rent::default())?;
+/// ```
+#[derive(Clone, Debug)]
+pub struct MintBuilder {
+    token_program_id: Pubkey,
+    mint: Pubkey,
+    mint_authority: Pubkey,
+    freeze_authority: Option<Pubkey>,
+    decimals: u8,
+    extensions: Vec<MintExtension>,
+}
+
+impl MintBuilder {
+    /// Start building a mint with no extensions and no freeze authority
+    pub fn new(
+        token_program_id: &Pubkey,
+        mint: &Pubkey,
+        mint_authority: &Pubkey,
+        decimals: u8,
+    ) -> Self {
+        Self {
+            token_program_id: *token_program_id,
+            mint: *mint,
+            mint_authority: *mint_authority,
+            freeze_authority: None,
+            decimals,
+            extensions: vec![],
+        }
+    }
+
+    /// Set the freeze authority of the mint
+    pub fn freeze_authority(mut self, freeze_authority: &Pubkey) -> Self {
+        self.freeze_authority = Some(*freeze_authority);
+        self
+    }
+
+    /// Add a mint extension
+    ///
+    /// Fails if the extension was already added
+    pub fn extension(mut self, extension: MintExtension) -> Result<Self, ProgramError> {
+        let extension_type = extension.extension();
+        if self
+            .extensions
+            .iter()
+            .any(|existing| existing.extension() == extension_type)
+        {
+            return Err(TokenError::ExtensionAlreadyInitialized.into());
+        }
+        self.extensions.push(extension);
+        Ok(self)
+    }
+
+    fn extension_types(&self) -> Vec<ExtensionType> {
+        self.extensions
+            .iter()
+            .map(MintExtension::extension)
+            .collect()
+    }
+
+    /// Get the account data length required for the mint and its extensions
+    pub fn space(&self) -> Result<usize, ProgramError> {
+        ExtensionType::try_calculate_account_len::<Mint>(&self.extension_types())
+    }
+
+    /// Create the instructions to allocate and initialize the mint, funded by
+    /// the payer with enough lamports to be rent-exempt
+    pub fn build(self, payer: &Pubkey, rent: &Rent) -> Result<Vec<Instruction>, ProgramError> {
+        ExtensionType::check_for_invalid_mint_extension_combinations(&self.extension_types())?;
+        let space = self.space()?;
+
+        let mut instructions = vec![system_instruction::create_account(
+            payer,
+            &self.mint,
+            rent.minimum_balance(space),
+            space as u64,
+            &self.token_program_id,
+        )];
+        for extension in self.extensions {
+            instructions.push(extension.instruction(&self.token_program_id, &self.mint)?);
+        }
+        instructions.push(initialize_mint2(
+            &self.token_program_id,
+            &self.mint,
+            &self.mint_authority,
+            self.freeze_authority.as_ref(),
+            self.decimals,
+        )?);
+        Ok(instructions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::extension::{max_supply, pausable},
+    };
+
+    fn builder() -> MintBuilder {
+        MintBuilder::new(
+            &crate::id(),
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            6,
+        )
+    }
+
+    #[test]
+    fn test_build() {
+        let payer = Pubkey::new_unique();
+        let rent = Rent::default();
+
+        let instructions = builder().build(&payer, &rent).unwrap();
+        assert_eq!(instructions.len(), 2);
+        assert_eq!(instructions[1].program_id, crate::id());
+
+        let builder = builder()
+            .freeze_authority(&Pubkey::new_unique())
+            .extension(MintExtension::MaxSupplyConfig {
+                authority: None,
+                max_supply: 100,
+            })
+            .unwrap()
+            .extension(MintExtension::PausableConfig { authority: None })
+            .unwrap();
+        let space = builder.space().unwrap();
+        assert_eq!(
+            space,
+            ExtensionType::try_calculate_account_len::<Mint>(&[
+                ExtensionType::MaxSupplyConfig,
+                ExtensionType::PausableConfig,
+            ])
+            .unwrap()
+        );
+        let mint = builder.mint;
+        let instructions = builder.build(&payer, &rent).unwrap();
+        assert_eq!(
+            instructions[0],
+            system_instruction::create_account(
+                &payer,
+                &mint,
+                rent.minimum_balance(space),
+                space as u64,
+                &crate::id(),
+            )
+        );
+        assert_eq!(
+            instructions[1],
+            max_supply::instruction::initialize(&crate::id(), &mint, None, 100).unwrap()
+        );
+        assert_eq!(
+            instructions[2],
+            pausable::instruction::initialize(&crate::id(), &mint, None).unwrap()
+        );
+        assert_eq!(instructions.len(), 4);
+    }
+
+    #[test]
+    fn test_invalid_extensions() {
+        assert_eq!(
+            builder()
+                .extension(MintExtension::NonTransferable)
+                .unwrap()
+                .extension(MintExtension::NonTransferable)
+                .unwrap_err(),
+            TokenError::ExtensionAlreadyInitialized.into()
+        );
+        assert_eq!(
+            builder()
+                .extension(MintExtension::NonTransferable)
+                .unwrap()
+                .extension(MintExtension::SoulBound)
+                .unwrap()
+                .build(&Pubkey::new_unique(), &Rent::default())
+                .unwrap_err(),
+            TokenError::InvalidExtensionCombination.into()
+        );
+    }
+}
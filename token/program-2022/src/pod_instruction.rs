@@ -114,6 +114,21 @@ pub(crate) enum PodTokenInstruction {
     // 40
     GroupPointerExtension,
     GroupMemberPointerExtension,
+    MaxSupplyExtension,
+    VestingExtension,
+    TransferRestrictionExtension,
+    // 45
+    TransferLimitExtension,
+    SnapshotExtension,
+    DistributionExtension,
+    ApprovalExpiryExtension,
+    SecondaryAuthorityExtension,
+    // 50
+    RoyaltyExtension,
+    TimedFreezeExtension,
+    SoulBoundExtension,
+    PendingTransferExtension,
+    PausableExtension,
 }
 
 fn unpack_pubkey_option(input: &[u8]) -> Result<PodCOption<Pubkey>, ProgramError> {
@@ -5,24 +5,40 @@ use {
         check_program_account, cmp_pubkeys,
         error::TokenError,
         extension::{
+            approval_expiry::{self, ApprovalExpiry},
             confidential_transfer::{self, ConfidentialTransferAccount, ConfidentialTransferMint},
             confidential_transfer_fee::{
                 self, ConfidentialTransferFeeAmount, ConfidentialTransferFeeConfig,
             },
             cpi_guard::{self, in_cpi, CpiGuard},
             default_account_state::{self, DefaultAccountState},
+            distribution::{self, DistributionConfig},
             group_member_pointer::{self, GroupMemberPointer},
             group_pointer::{self, GroupPointer},
             immutable_owner::ImmutableOwner,
             interest_bearing_mint::{self, InterestBearingConfig},
+            max_supply::{self, MaxSupplyConfig},
             memo_transfer::{self, check_previous_sibling_instruction_is_memo, memo_required},
             metadata_pointer::{self, MetadataPointer},
             mint_close_authority::MintCloseAuthority,
             non_transferable::{NonTransferable, NonTransferableAccount},
+            pausable::{self, check_not_paused, PausableAccount, PausableConfig},
+            pending_transfer::{self, PendingTransfer},
             permanent_delegate::{get_permanent_delegate, PermanentDelegate},
-            reallocate, token_group, token_metadata,
+            reallocate,
+            royalty::{self, RoyaltyAccount, RoyaltyConfig},
+            secondary_authority::{self, get_secondary_authority},
+            snapshot::{self, write_checkpoint, SnapshotAccount, SnapshotConfig},
+            soul_bound::{self, SoulBound, SoulBoundAccount},
+            timed_freeze::{self, TimedFreeze},
+            token_group, token_metadata,
             transfer_fee::{self, TransferFeeAmount, TransferFeeConfig},
             transfer_hook::{self, TransferHook, TransferHookAccount},
+            transfer_limit::{self, TransferLimitAccount, TransferLimitConfig},
+            transfer_restriction::{
+                self, check_transfer_restriction, TransferRestriction, TransferRestrictionConfig,
+            },
+            vesting::{self, VestingAccount, VestingConfig},
             AccountType, BaseStateWithExtensions, BaseStateWithExtensionsMut, ExtensionType,
             PodStateWithExtensions, PodStateWithExtensionsMut,
         },
@@ -90,6 +106,13 @@ impl Processor {
         }
         ExtensionType::check_for_invalid_mint_extension_combinations(&extension_types)?;
 
+        if let (Ok(royalty_config), Ok(transfer_fee_config)) = (
+            mint.get_extension::<RoyaltyConfig>(),
+            mint.get_extension::<TransferFeeConfig>(),
+        ) {
+            royalty_config.check_transfer_fee(transfer_fee_config)?;
+        }
+
         if let Ok(default_account_state) = mint.get_extension_mut::<DefaultAccountState>() {
             let default_account_state = AccountState::try_from(default_account_state.state)
                 .or(Err(ProgramError::InvalidAccountData))?;
@@ -316,60 +339,121 @@ impl Processor {
         if source_account
             .get_extension::<NonTransferableAccount>()
             .is_ok()
+            || source_account.get_extension::<SoulBoundAccount>().is_ok()
         {
             return Err(TokenError::NonTransferable.into());
         }
-        let (fee, maybe_permanent_delegate, maybe_transfer_hook_program_id) =
-            if let Some((mint_info, expected_decimals)) = expected_mint_info {
-                if !cmp_pubkeys(&source_account.base.mint, mint_info.key) {
-                    return Err(TokenError::MintMismatch.into());
-                }
+        if let Ok(extension) = source_account.get_extension::<VestingAccount>() {
+            extension.check_available(source_amount, amount, Clock::get()?.unix_timestamp)?;
+        }
+        let (
+            fee,
+            maybe_permanent_delegate,
+            maybe_transfer_hook_program_id,
+            maybe_transfer_restriction,
+            maybe_transfer_limit,
+            maybe_snapshot,
+            maybe_royalty,
+        ) = if let Some((mint_info, expected_decimals)) = expected_mint_info {
+            if !cmp_pubkeys(&source_account.base.mint, mint_info.key) {
+                return Err(TokenError::MintMismatch.into());
+            }
 
-                let mint_data = mint_info.try_borrow_data()?;
-                let mint = PodStateWithExtensions::<PodMint>::unpack(&mint_data)?;
+            let mint_data = mint_info.try_borrow_data()?;
+            let mint = PodStateWithExtensions::<PodMint>::unpack(&mint_data)?;
 
-                if expected_decimals != mint.base.decimals {
-                    return Err(TokenError::MintDecimalsMismatch.into());
-                }
+            if expected_decimals != mint.base.decimals {
+                return Err(TokenError::MintDecimalsMismatch.into());
+            }
+            check_not_paused(&mint)?;
 
-                let fee = if let Ok(transfer_fee_config) = mint.get_extension::<TransferFeeConfig>()
-                {
-                    transfer_fee_config
-                        .calculate_epoch_fee(Clock::get()?.epoch, amount)
-                        .ok_or(TokenError::Overflow)?
-                } else {
-                    0
-                };
+            let fee = if let Ok(transfer_fee_config) = mint.get_extension::<TransferFeeConfig>() {
+                transfer_fee_config
+                    .calculate_epoch_fee(Clock::get()?.epoch, amount)
+                    .ok_or(TokenError::Overflow)?
+            } else {
+                0
+            };
 
-                let maybe_permanent_delegate = get_permanent_delegate(&mint);
-                let maybe_transfer_hook_program_id = transfer_hook::get_program_id(&mint);
+            let maybe_permanent_delegate = get_permanent_delegate(&mint);
+            let maybe_transfer_hook_program_id = transfer_hook::get_program_id(&mint);
+            let maybe_transfer_restriction = mint
+                .get_extension::<TransferRestrictionConfig>()
+                .ok()
+                .copied();
+            let maybe_transfer_limit = mint.get_extension::<TransferLimitConfig>().ok().copied();
+            let maybe_snapshot = mint.get_extension::<SnapshotConfig>().ok().copied();
+            let maybe_royalty = mint.get_extension::<RoyaltyConfig>().ok().copied();
 
-                (
-                    fee,
-                    maybe_permanent_delegate,
-                    maybe_transfer_hook_program_id,
-                )
-            } else {
-                // Transfer hook extension exists on the account, but no mint
-                // was provided to figure out required accounts, abort
-                if source_account
-                    .get_extension::<TransferHookAccount>()
-                    .is_ok()
-                {
-                    return Err(TokenError::MintRequiredForTransfer.into());
-                }
+            (
+                fee,
+                maybe_permanent_delegate,
+                maybe_transfer_hook_program_id,
+                maybe_transfer_restriction,
+                maybe_transfer_limit,
+                maybe_snapshot,
+                maybe_royalty,
+            )
+        } else {
+            // Transfer hook extension exists on the account, but no mint
+            // was provided to figure out required accounts, abort
+            if source_account
+                .get_extension::<TransferHookAccount>()
+                .is_ok()
+            {
+                return Err(TokenError::MintRequiredForTransfer.into());
+            }
 
-                // Transfer fee amount extension exists on the account, but no mint
-                // was provided to calculate the fee, abort
-                if source_account
-                    .get_extension_mut::<TransferFeeAmount>()
-                    .is_ok()
-                {
-                    return Err(TokenError::MintRequiredForTransfer.into());
-                } else {
-                    (0, None, None)
-                }
-            };
+            // Transfer restriction extension exists on the account, but no
+            // mint was provided to check the lists against, abort
+            if source_account
+                .get_extension::<TransferRestriction>()
+                .is_ok()
+            {
+                return Err(TokenError::MintRequiredForTransfer.into());
+            }
+
+            // Transfer limit extension exists on the account, but no mint was
+            // provided to check the limits against, abort
+            if source_account
+                .get_extension::<TransferLimitAccount>()
+                .is_ok()
+            {
+                return Err(TokenError::MintRequiredForTransfer.into());
+            }
+
+            // Snapshot extension exists on the account, but no mint was
+            // provided to find the latest snapshot, abort
+            if source_account.get_extension::<SnapshotAccount>().is_ok() {
+                return Err(TokenError::MintRequiredForTransfer.into());
+            }
+
+            // Royalty extension exists on the account, but no mint was
+            // provided to calculate the royalties, abort
+            if source_account.get_extension::<RoyaltyAccount>().is_ok() {
+                return Err(TokenError::MintRequiredForTransfer.into());
+            }
+
+            // Pausable extension exists on the account, but no mint was
+            // provided to check if it is paused, abort
+            if source_account.get_extension::<PausableAccount>().is_ok() {
+                return Err(TokenError::MintRequiredForTransfer.into());
+            }
+
+            // Transfer fee amount extension exists on the account, but no mint
+            // was provided to calculate the fee, abort
+            if source_account
+                .get_extension_mut::<TransferFeeAmount>()
+                .is_ok()
+            {
+                return Err(TokenError::MintRequiredForTransfer.into());
+            } else {
+                (0, None, None, None, None, None, None)
+            }
+        };
+        if let Some(ref transfer_restriction) = maybe_transfer_restriction {
+            check_transfer_restriction(transfer_restriction, &source_account)?;
+        }
         if let Some(expected_fee) = expected_fee {
             if expected_fee != fee {
                 msg!("Calculated fee {}, received {}", fee, expected_fee);
@@ -378,8 +462,13 @@ impl Processor {
         }
 
         let self_transfer = cmp_pubkeys(source_account_info.key, destination_account_info.key);
-        match (source_account.base.delegate, maybe_permanent_delegate) {
-            (_, Some(ref delegate)) if cmp_pubkeys(authority_info.key, delegate) => {
+        let maybe_secondary_authority = get_secondary_authority(&source_account);
+        match (
+            source_account.base.delegate,
+            maybe_permanent_delegate,
+            maybe_secondary_authority,
+        ) {
+            (_, Some(ref delegate), _) if cmp_pubkeys(authority_info.key, delegate) => {
                 Self::validate_owner(
                     program_id,
                     delegate,
@@ -394,6 +483,7 @@ impl Processor {
                     value: delegate,
                 },
                 _,
+                _,
             ) if cmp_pubkeys(authority_info.key, &delegate) => {
                 Self::validate_owner(
                     program_id,
@@ -402,6 +492,9 @@ impl Processor {
                     authority_info_data_len,
                     account_info_iter.as_slice(),
                 )?;
+                if let Ok(approval_expiry) = source_account.get_extension::<ApprovalExpiry>() {
+                    approval_expiry.check(Clock::get()?.slot)?;
+                }
                 let delegated_amount = u64::from(source_account.base.delegated_amount);
                 if delegated_amount < amount {
                     return Err(TokenError::InsufficientFunds.into());
@@ -416,6 +509,20 @@ impl Processor {
                     }
                 }
             }
+            (_, _, Some((ref secondary_authority, max_transfer_amount)))
+                if cmp_pubkeys(authority_info.key, secondary_authority) =>
+            {
+                Self::validate_owner(
+                    program_id,
+                    secondary_authority,
+                    authority_info,
+                    authority_info_data_len,
+                    account_info_iter.as_slice(),
+                )?;
+                if amount > max_transfer_amount {
+                    return Err(TokenError::SecondaryAuthorityLimitExceeded.into());
+                }
+            }
             _ => {
                 Self::validate_owner(
                     program_id,
@@ -456,6 +563,9 @@ impl Processor {
         if !cmp_pubkeys(&source_account.base.mint, &destination_account.base.mint) {
             return Err(TokenError::MintMismatch.into());
         }
+        if let Some(ref transfer_restriction) = maybe_transfer_restriction {
+            check_transfer_restriction(transfer_restriction, &destination_account)?;
+        }
 
         if memo_required(&destination_account) {
             check_previous_sibling_instruction_is_memo()?;
@@ -467,11 +577,33 @@ impl Processor {
             confidential_transfer_state.non_confidential_transfer_allowed()?
         }
 
+        if let Some(ref transfer_limit) = maybe_transfer_limit {
+            source_account
+                .get_extension_mut::<TransferLimitAccount>()?
+                .record_transfer(transfer_limit, amount, Clock::get()?.unix_timestamp)?;
+        }
+
+        if let Some(ref snapshot) = maybe_snapshot {
+            write_checkpoint(snapshot, &mut source_account)?;
+            write_checkpoint(snapshot, &mut destination_account)?;
+        }
+
         source_account.base.amount = source_amount
             .checked_sub(amount)
             .ok_or(TokenError::Overflow)?
             .into();
-        let credited_amount = amount.checked_sub(fee).ok_or(TokenError::Overflow)?;
+        let mut credited_amount = amount.checked_sub(fee).ok_or(TokenError::Overflow)?;
+        if let Some(ref royalty) = maybe_royalty {
+            let royalties = royalty
+                .calculate_royalties(amount)
+                .ok_or(TokenError::Overflow)?;
+            credited_amount = credited_amount
+                .checked_sub(royalties.iter().sum())
+                .ok_or(TokenError::Overflow)?;
+            destination_account
+                .get_extension_mut::<RoyaltyAccount>()?
+                .withhold(&royalties)?;
+        }
         destination_account.base.amount = u64::from(destination_account.base.amount)
             .checked_add(credited_amount)
             .ok_or(TokenError::Overflow)?
@@ -553,7 +685,7 @@ impl Processor {
         let owner_info_data_len = owner_info.data_len();
 
         let mut source_account_data = source_account_info.data.borrow_mut();
-        let source_account =
+        let mut source_account =
             PodStateWithExtensionsMut::<PodAccount>::unpack(&mut source_account_data)?;
 
         if source_account.base.is_frozen() {
@@ -588,6 +720,9 @@ impl Processor {
 
         source_account.base.delegate = PodCOption::some(*delegate_info.key);
         source_account.base.delegated_amount = amount.into();
+        if let Ok(approval_expiry) = source_account.get_extension_mut::<ApprovalExpiry>() {
+            approval_expiry.expiry_slot = 0.into();
+        }
 
         Ok(())
     }
@@ -679,6 +814,11 @@ impl Processor {
 
                     account.base.delegate = PodCOption::none();
                     account.base.delegated_amount = 0.into();
+                    if let Ok(extension) =
+                        account.get_extension_mut::<secondary_authority::SecondaryAuthority>()
+                    {
+                        *extension = secondary_authority::SecondaryAuthority::default();
+                    }
 
                     if account.base.is_native() {
                         account.base.close_authority = PodCOption::none();
@@ -893,6 +1033,110 @@ impl Processor {
                     )?;
                     extension.authority = new_authority.try_into()?;
                 }
+                AuthorityType::MaxSupply => {
+                    let extension = mint.get_extension_mut::<MaxSupplyConfig>()?;
+                    let maybe_authority: Option<Pubkey> = extension.authority.into();
+                    let authority = maybe_authority.ok_or(TokenError::AuthorityTypeNotSupported)?;
+                    Self::validate_owner(
+                        program_id,
+                        &authority,
+                        authority_info,
+                        authority_info_data_len,
+                        account_info_iter.as_slice(),
+                    )?;
+                    extension.authority = new_authority.try_into()?;
+                }
+                AuthorityType::Vesting => {
+                    let extension = mint.get_extension_mut::<VestingConfig>()?;
+                    let maybe_authority: Option<Pubkey> = extension.authority.into();
+                    let authority = maybe_authority.ok_or(TokenError::AuthorityTypeNotSupported)?;
+                    Self::validate_owner(
+                        program_id,
+                        &authority,
+                        authority_info,
+                        authority_info_data_len,
+                        account_info_iter.as_slice(),
+                    )?;
+                    extension.authority = new_authority.try_into()?;
+                }
+                AuthorityType::TransferRestriction => {
+                    let extension = mint.get_extension_mut::<TransferRestrictionConfig>()?;
+                    let maybe_authority: Option<Pubkey> = extension.authority.into();
+                    let authority = maybe_authority.ok_or(TokenError::AuthorityTypeNotSupported)?;
+                    Self::validate_owner(
+                        program_id,
+                        &authority,
+                        authority_info,
+                        authority_info_data_len,
+                        account_info_iter.as_slice(),
+                    )?;
+                    extension.authority = new_authority.try_into()?;
+                }
+                AuthorityType::TransferLimit => {
+                    let extension = mint.get_extension_mut::<TransferLimitConfig>()?;
+                    let maybe_authority: Option<Pubkey> = extension.authority.into();
+                    let authority = maybe_authority.ok_or(TokenError::AuthorityTypeNotSupported)?;
+                    Self::validate_owner(
+                        program_id,
+                        &authority,
+                        authority_info,
+                        authority_info_data_len,
+                        account_info_iter.as_slice(),
+                    )?;
+                    extension.authority = new_authority.try_into()?;
+                }
+                AuthorityType::Snapshot => {
+                    let extension = mint.get_extension_mut::<SnapshotConfig>()?;
+                    let maybe_authority: Option<Pubkey> = extension.authority.into();
+                    let authority = maybe_authority.ok_or(TokenError::AuthorityTypeNotSupported)?;
+                    Self::validate_owner(
+                        program_id,
+                        &authority,
+                        authority_info,
+                        authority_info_data_len,
+                        account_info_iter.as_slice(),
+                    )?;
+                    extension.authority = new_authority.try_into()?;
+                }
+                AuthorityType::Distribution => {
+                    let extension = mint.get_extension_mut::<DistributionConfig>()?;
+                    let maybe_authority: Option<Pubkey> = extension.authority.into();
+                    let authority = maybe_authority.ok_or(TokenError::AuthorityTypeNotSupported)?;
+                    Self::validate_owner(
+                        program_id,
+                        &authority,
+                        authority_info,
+                        authority_info_data_len,
+                        account_info_iter.as_slice(),
+                    )?;
+                    extension.authority = new_authority.try_into()?;
+                }
+                AuthorityType::Royalty => {
+                    let extension = mint.get_extension_mut::<RoyaltyConfig>()?;
+                    let maybe_authority: Option<Pubkey> = extension.authority.into();
+                    let authority = maybe_authority.ok_or(TokenError::AuthorityTypeNotSupported)?;
+                    Self::validate_owner(
+                        program_id,
+                        &authority,
+                        authority_info,
+                        authority_info_data_len,
+                        account_info_iter.as_slice(),
+                    )?;
+                    extension.authority = new_authority.try_into()?;
+                }
+                AuthorityType::Pause => {
+                    let extension = mint.get_extension_mut::<PausableConfig>()?;
+                    let maybe_authority: Option<Pubkey> = extension.authority.into();
+                    let authority = maybe_authority.ok_or(TokenError::AuthorityTypeNotSupported)?;
+                    Self::validate_owner(
+                        program_id,
+                        &authority,
+                        authority_info,
+                        authority_info_data_len,
+                        account_info_iter.as_slice(),
+                    )?;
+                    extension.authority = new_authority.try_into()?;
+                }
                 _ => {
                     return Err(TokenError::AuthorityTypeNotSupported.into());
                 }
@@ -918,7 +1162,7 @@ impl Processor {
         let owner_info_data_len = owner_info.data_len();
 
         let mut destination_account_data = destination_account_info.data.borrow_mut();
-        let destination_account =
+        let mut destination_account =
             PodStateWithExtensionsMut::<PodAccount>::unpack(&mut destination_account_data)?;
         if destination_account.base.is_frozen() {
             return Err(TokenError::AccountFrozen.into());
@@ -936,7 +1180,8 @@ impl Processor {
 
         // If the mint if non-transferable, only allow minting to accounts
         // with immutable ownership.
-        if mint.get_extension::<NonTransferable>().is_ok()
+        if (mint.get_extension::<NonTransferable>().is_ok()
+            || mint.get_extension::<SoulBound>().is_ok())
             && destination_account
                 .get_extension::<ImmutableOwner>()
                 .is_err()
@@ -949,6 +1194,7 @@ impl Processor {
                 return Err(TokenError::MintDecimalsMismatch.into());
             }
         }
+        check_not_paused(&mint)?;
 
         match &mint.base.mint_authority {
             PodCOption {
@@ -970,16 +1216,23 @@ impl Processor {
         check_program_account(mint_info.owner)?;
         check_program_account(destination_account_info.owner)?;
 
-        destination_account.base.amount = u64::from(destination_account.base.amount)
+        let new_supply = u64::from(mint.base.supply)
             .checked_add(amount)
-            .ok_or(TokenError::Overflow)?
-            .into();
+            .ok_or(TokenError::Overflow)?;
+        if let Ok(extension) = mint.get_extension::<MaxSupplyConfig>() {
+            extension.check_supply(new_supply)?;
+        }
+        if let Ok(extension) = mint.get_extension::<SnapshotConfig>() {
+            write_checkpoint(extension, &mut destination_account)?;
+        }
 
-        mint.base.supply = u64::from(mint.base.supply)
+        destination_account.base.amount = u64::from(destination_account.base.amount)
             .checked_add(amount)
             .ok_or(TokenError::Overflow)?
             .into();
 
+        mint.base.supply = new_supply.into();
+
         Ok(())
     }
 
@@ -998,7 +1251,7 @@ impl Processor {
         let authority_info_data_len = authority_info.data_len();
 
         let mut source_account_data = source_account_info.data.borrow_mut();
-        let source_account =
+        let mut source_account =
             PodStateWithExtensionsMut::<PodAccount>::unpack(&mut source_account_data)?;
         let mut mint_data = mint_info.data.borrow_mut();
         let mint = PodStateWithExtensionsMut::<PodMint>::unpack(&mut mint_data)?;
@@ -1012,6 +1265,13 @@ impl Processor {
         if u64::from(source_account.base.amount) < amount {
             return Err(TokenError::InsufficientFunds.into());
         }
+        if let Ok(extension) = source_account.get_extension::<VestingAccount>() {
+            extension.check_available(
+                source_account.base.amount.into(),
+                amount,
+                Clock::get()?.unix_timestamp,
+            )?;
+        }
         if mint_info.key != &source_account.base.mint {
             return Err(TokenError::MintMismatch.into());
         }
@@ -1021,6 +1281,7 @@ impl Processor {
                 return Err(TokenError::MintDecimalsMismatch.into());
             }
         }
+        check_not_paused(&mint)?;
         let maybe_permanent_delegate = get_permanent_delegate(&mint);
 
         if !source_account
@@ -1051,6 +1312,9 @@ impl Processor {
                         authority_info_data_len,
                         account_info_iter.as_slice(),
                     )?;
+                    if let Ok(approval_expiry) = source_account.get_extension::<ApprovalExpiry>() {
+                        approval_expiry.check(Clock::get()?.slot)?;
+                    }
 
                     if u64::from(source_account.base.delegated_amount) < amount {
                         return Err(TokenError::InsufficientFunds.into());
@@ -1088,6 +1352,10 @@ impl Processor {
         check_program_account(source_account_info.owner)?;
         check_program_account(mint_info.owner)?;
 
+        if let Ok(extension) = mint.get_extension::<SnapshotConfig>() {
+            write_checkpoint(extension, &mut source_account)?;
+        }
+
         source_account.base.amount = u64::from(source_account.base.amount)
             .checked_sub(amount)
             .ok_or(TokenError::Overflow)?
@@ -1164,6 +1432,14 @@ impl Processor {
             if let Ok(transfer_fee_state) = source_account.get_extension::<TransferFeeAmount>() {
                 transfer_fee_state.closable()?
             }
+
+            if let Ok(royalty_state) = source_account.get_extension::<RoyaltyAccount>() {
+                royalty_state.closable()?
+            }
+
+            if let Ok(pending_transfer_state) = source_account.get_extension::<PendingTransfer>() {
+                pending_transfer_state.closable()?
+            }
         } else if let Ok(mint) = PodStateWithExtensions::<PodMint>::unpack(&source_account_data) {
             let extension = mint.get_extension::<MintCloseAuthority>()?;
             let maybe_authority: Option<Pubkey> = extension.close_authority.into();
@@ -1209,7 +1485,7 @@ impl Processor {
         let authority_info_data_len = authority_info.data_len();
 
         let mut source_account_data = source_account_info.data.borrow_mut();
-        let source_account =
+        let mut source_account =
             PodStateWithExtensionsMut::<PodAccount>::unpack(&mut source_account_data)?;
         if freeze && source_account.base.is_frozen() || !freeze && !source_account.base.is_frozen()
         {
@@ -1243,6 +1519,9 @@ impl Processor {
         } else {
             AccountState::Initialized.into()
         };
+        if let Ok(timed_freeze) = source_account.get_extension_mut::<TimedFreeze>() {
+            timed_freeze.thaw_timestamp = 0.into();
+        }
 
         Ok(())
     }
@@ -1778,6 +2057,65 @@ impl Processor {
                         &input[1..],
                     )
                 }
+                PodTokenInstruction::MaxSupplyExtension => {
+                    max_supply::processor::process_instruction(program_id, accounts, &input[1..])
+                }
+                PodTokenInstruction::VestingExtension => {
+                    vesting::processor::process_instruction(program_id, accounts, &input[1..])
+                }
+                PodTokenInstruction::TransferRestrictionExtension => {
+                    transfer_restriction::processor::process_instruction(
+                        program_id,
+                        accounts,
+                        &input[1..],
+                    )
+                }
+                PodTokenInstruction::TransferLimitExtension => {
+                    transfer_limit::processor::process_instruction(
+                        program_id,
+                        accounts,
+                        &input[1..],
+                    )
+                }
+                PodTokenInstruction::SnapshotExtension => {
+                    snapshot::processor::process_instruction(program_id, accounts, &input[1..])
+                }
+                PodTokenInstruction::DistributionExtension => {
+                    distribution::processor::process_instruction(program_id, accounts, &input[1..])
+                }
+                PodTokenInstruction::ApprovalExpiryExtension => {
+                    approval_expiry::processor::process_instruction(
+                        program_id,
+                        accounts,
+                        &input[1..],
+                    )
+                }
+                PodTokenInstruction::SecondaryAuthorityExtension => {
+                    secondary_authority::processor::process_instruction(
+                        program_id,
+                        accounts,
+                        &input[1..],
+                    )
+                }
+                PodTokenInstruction::RoyaltyExtension => {
+                    royalty::processor::process_instruction(program_id, accounts, &input[1..])
+                }
+                PodTokenInstruction::TimedFreezeExtension => {
+                    timed_freeze::processor::process_instruction(program_id, accounts, &input[1..])
+                }
+                PodTokenInstruction::SoulBoundExtension => {
+                    soul_bound::processor::process_instruction(program_id, accounts, &input[1..])
+                }
+                PodTokenInstruction::PendingTransferExtension => {
+                    pending_transfer::processor::process_instruction(
+                        program_id,
+                        accounts,
+                        &input[1..],
+                    )
+                }
+                PodTokenInstruction::PausableExtension => {
+                    pausable::processor::process_instruction(program_id, accounts, &input[1..])
+                }
             }
         } else if let Ok(instruction) = TokenMetadataInstruction::unpack(input) {
             token_metadata::processor::process_instruction(program_id, accounts, instruction)
@@ -0,0 +1,237 @@
+//! Human-readable summaries of token instructions, for approval screens
+
+use {
+    crate::{
+        check_program_account, format_ui_amount, instruction::TokenInstruction, UiAmountFormat,
+    },
+    solana_program::{
+        instruction::Instruction, program_error::ProgramError, program_option::COption,
+        pubkey::Pubkey,
+    },
+};
+
+/// Display-ready description of a token instruction
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InstructionSummary {
+    /// Short name of the action, ie. "Transfer"
+    pub action: &'static str,
+    /// Sentence describing the action and the accounts involved
+    pub description: String,
+}
+
+fn account_key(instruction: &Instruction, index: usize) -> Result<Pubkey, ProgramError> {
+    instruction
+        .accounts
+        .get(index)
+        .map(|account_meta| account_meta.pubkey)
+        .ok_or(ProgramError::NotEnoughAccountKeys)
+}
+
+/// Format an amount in tokens if the decimals are known, or in base units
+/// otherwise
+fn format_amount(amount: u64, decimals: Option<u8>) -> String {
+    match decimals {
+        Some(decimals) => {
+            let format = UiAmountFormat {
+                thousands_separator: true,
+                trim_trailing_zeros: true,
+            };
+            format!("{} tokens", format_ui_amount(amount, decimals, format))
+        }
+        None => format!("{} base units", amount),
+    }
+}
+
+/// Summarize a token instruction for display.
+///
+/// `decimals` is the mint's decimals, used to show amounts in tokens for
+/// instructions that do not carry the decimals themselves. Returns `None` for
+/// instructions that have no summary, which should be displayed as-is. Fails
+/// with `IncorrectProgramId` if the instruction is not for this program.
+pub fn summarize_instruction(
+    instruction: &Instruction,
+    decimals: Option<u8>,
+) -> Result<Option<InstructionSummary>, ProgramError> {
+    check_program_account(&instruction.program_id)?;
+    let key = |index| account_key(instruction, index);
+    let (action, description) = match TokenInstruction::unpack(&instruction.data)? {
+        #[allow(deprecated)]
+        TokenInstruction::Transfer { amount } => (
+            "Transfer",
+            format!(
+                "Transfer {} from {} to {}",
+                format_amount(amount, decimals),
+                key(0)?,
+                key(1)?
+            ),
+        ),
+        TokenInstruction::TransferChecked { amount, decimals } => (
+            "Transfer",
+            format!(
+                "Transfer {} from {} to {}",
+                format_amount(amount, Some(decimals)),
+                key(0)?,
+                key(2)?
+            ),
+        ),
+        TokenInstruction::Approve { amount } => (
+            "Approve",
+            format!(
+                "Allow {} to spend {} from {}",
+                key(1)?,
+                format_amount(amount, decimals),
+                key(0)?
+            ),
+        ),
+        TokenInstruction::ApproveChecked { amount, decimals } => (
+            "Approve",
+            format!(
+                "Allow {} to spend {} from {}",
+                key(2)?,
+                format_amount(amount, Some(decimals)),
+                key(0)?
+            ),
+        ),
+        TokenInstruction::Revoke => ("Revoke", format!("Remove the delegate of {}", key(0)?)),
+        TokenInstruction::MintTo { amount } => (
+            "Mint",
+            format!("Mint {} to {}", format_amount(amount, decimals), key(1)?),
+        ),
+        TokenInstruction::MintToChecked { amount, decimals } => (
+            "Mint",
+            format!(
+                "Mint {} to {}",
+                format_amount(amount, Some(decimals)),
+                key(1)?
+            ),
+        ),
+        TokenInstruction::Burn { amount } => (
+            "Burn",
+            format!("Burn {} from {}", format_amount(amount, decimals), key(0)?),
+        ),
+        TokenInstruction::BurnChecked { amount, decimals } => (
+            "Burn",
+            format!(
+                "Burn {} from {}",
+                format_amount(amount, Some(decimals)),
+                key(0)?
+            ),
+        ),
+        TokenInstruction::CloseAccount => (
+            "Close",
+            format!("Close {} and send its rent to {}", key(0)?, key(1)?),
+        ),
+        TokenInstruction::FreezeAccount => ("Freeze", format!("Freeze {}", key(0)?)),
+        TokenInstruction::ThawAccount => ("Thaw", format!("Thaw {}", key(0)?)),
+        TokenInstruction::SetAuthority {
+            authority_type,
+            new_authority,
+        } => (
+            "Set authority",
+            match new_authority {
+                COption::Some(new_authority) => format!(
+                    "Set the {:?} authority of {} to {}",
+                    authority_type,
+                    key(0)?,
+                    new_authority
+                ),
+                COption::None => {
+                    format!("Remove the {:?} authority of {}", authority_type, key(0)?)
+                }
+            },
+        ),
+        _ => return Ok(None),
+    };
+    Ok(Some(InstructionSummary {
+        action,
+        description,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::instruction};
+
+    #[test]
+    fn test_summarize_instruction() {
+        let program_id = crate::id();
+        let source = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let destination = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+
+        let transfer_checked = instruction::transfer_checked(
+            &program_id,
+            &source,
+            &mint,
+            &destination,
+            &owner,
+            &[],
+            1_234_567_890,
+            3,
+        )
+        .unwrap();
+        assert_eq!(
+            summarize_instruction(&transfer_checked, None).unwrap(),
+            Some(InstructionSummary {
+                action: "Transfer",
+                description: format!(
+                    "Transfer 1,234,567.89 tokens from {} to {}",
+                    source, destination
+                ),
+            })
+        );
+
+        #[allow(deprecated)]
+        let transfer =
+            instruction::transfer(&program_id, &source, &destination, &owner, &[], 500).unwrap();
+        assert_eq!(
+            summarize_instruction(&transfer, None)
+                .unwrap()
+                .unwrap()
+                .description,
+            format!("Transfer 500 base units from {} to {}", source, destination)
+        );
+        assert_eq!(
+            summarize_instruction(&transfer, Some(2))
+                .unwrap()
+                .unwrap()
+                .description,
+            format!("Transfer 5 tokens from {} to {}", source, destination)
+        );
+
+        let set_authority = instruction::set_authority(
+            &program_id,
+            &mint,
+            None,
+            instruction::AuthorityType::MintTokens,
+            &owner,
+            &[],
+        )
+        .unwrap();
+        assert_eq!(
+            summarize_instruction(&set_authority, None)
+                .unwrap()
+                .unwrap()
+                .description,
+            format!("Remove the MintTokens authority of {}", mint)
+        );
+
+        let sync_native = instruction::sync_native(&program_id, &source).unwrap();
+        assert_eq!(summarize_instruction(&sync_native, None).unwrap(), None);
+
+        let mut foreign = transfer_checked.clone();
+        foreign.program_id = spl_token::id();
+        assert_eq!(
+            summarize_instruction(&foreign, None).unwrap_err(),
+            ProgramError::IncorrectProgramId
+        );
+
+        let mut truncated = transfer_checked;
+        truncated.accounts.truncate(2);
+        assert_eq!(
+            summarize_instruction(&truncated, None).unwrap_err(),
+            ProgramError::NotEnoughAccountKeys
+        );
+    }
+}